@@ -0,0 +1,197 @@
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, LitStr};
+
+/// Derives `FormEnum` for an enum with only unit variants.
+///
+/// The value defaults to the variant's name, and the label to the variant's
+/// name split into words. These can be changed with
+/// `#[form(value = "...")]` and `#[form(label = "...")]` on the variant.
+#[proc_macro_derive(FormEnum, attributes(form))]
+pub fn derive_form_enum(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match form_enum(input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn form_enum(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let Data::Enum(data) = input.data else {
+        return Err(Error::new(
+            Span::call_site(),
+            "FormEnum can only be derived for enums",
+        ));
+    };
+
+    let mut idents = Vec::new();
+    let mut values = Vec::<LitStr>::new();
+    let mut labels = Vec::<LitStr>::new();
+    for variant in data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new_spanned(
+                variant,
+                "FormEnum variants can't have fields",
+            ));
+        }
+
+        let name = variant.ident.to_string();
+        let mut value = LitStr::new(&name, variant.ident.span());
+        let mut label = LitStr::new(&split_words(&name), variant.ident.span());
+        for attr in variant.attrs.iter().filter(|a| a.path().is_ident("form")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("value") {
+                    value = meta.value()?.parse()?;
+                } else if meta.path.is_ident("label") {
+                    label = meta.value()?.parse()?;
+                } else {
+                    return Err(meta.error("expected `value` or `label`"));
+                }
+                Ok(())
+            })?;
+        }
+
+        if let Some(other) = values.iter().find(|v| v.value() == value.value()) {
+            let mut error = Error::new(value.span(), "FormEnum values must be unique");
+            error.combine(Error::new(other.span(), "the value is also used here"));
+            return Err(error);
+        }
+        idents.push(variant.ident);
+        values.push(value);
+        labels.push(label);
+    }
+
+    let name = input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::leptos_form_tool::FormEnum for #name #ty_generics #where_clause {
+            fn variants() -> &'static [Self] {
+                &[#(#name::#idents),*]
+            }
+
+            fn value(&self) -> &'static str {
+                match self {
+                    #(#name::#idents => #values,)*
+                }
+            }
+
+            fn label(&self) -> &'static str {
+                match self {
+                    #(#name::#idents => #labels,)*
+                }
+            }
+        }
+    })
+}
+
+/// Splits a CamelCase name into words, like "ExtraLarge" into "Extra Large".
+fn split_words(name: &str) -> String {
+    let mut words = String::new();
+    let mut prev: Option<char> = None;
+    let mut chars = name.chars().peekable();
+    while let Some(c) = chars.next() {
+        if let Some(prev) = prev {
+            let next_lower = chars.peek().is_some_and(|n| n.is_lowercase());
+            // after a digit or an acronym, a capital only starts a word
+            // when lowercase letters follow, so "Large2XL" stays together
+            let starts_word = c.is_uppercase()
+                && (prev.is_lowercase()
+                    || ((prev.is_numeric() || prev.is_uppercase()) && next_lower));
+            if starts_word || c == '_' {
+                words.push(' ');
+            }
+        }
+        if c != '_' {
+            words.push(c);
+        }
+        prev = Some(c);
+    }
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn split_words_splits_camel_case() {
+        assert_eq!(split_words("Small"), "Small");
+        assert_eq!(split_words("ExtraLarge"), "Extra Large");
+        assert_eq!(split_words("VeryExtraLarge"), "Very Extra Large");
+    }
+
+    #[test]
+    fn split_words_keeps_acronyms_together() {
+        assert_eq!(split_words("USA"), "USA");
+        assert_eq!(split_words("HTTPServer"), "HTTP Server");
+        assert_eq!(split_words("UseHTTP"), "Use HTTP");
+    }
+
+    #[test]
+    fn split_words_splits_after_digits() {
+        assert_eq!(split_words("Size2"), "Size2");
+        assert_eq!(split_words("Size2Large"), "Size2 Large");
+        assert_eq!(split_words("V2"), "V2");
+    }
+
+    #[test]
+    fn split_words_keeps_capitals_after_digits() {
+        assert_eq!(split_words("Large2X"), "Large2X");
+        assert_eq!(split_words("Size2XL"), "Size2XL");
+        assert_eq!(split_words("Mp3Player"), "Mp3 Player");
+    }
+
+    #[test]
+    fn split_words_replaces_underscores() {
+        assert_eq!(split_words("Extra_large"), "Extra large");
+        assert_eq!(split_words("A"), "A");
+        assert_eq!(split_words(""), "");
+    }
+
+    #[test]
+    fn fields_are_rejected() {
+        let input: DeriveInput = parse_quote! {
+            enum Shape {
+                Circle(f32),
+            }
+        };
+        let err = form_enum(input).unwrap_err();
+        assert_eq!(err.to_string(), "FormEnum variants can't have fields");
+    }
+
+    #[test]
+    fn structs_are_rejected() {
+        let input: DeriveInput = parse_quote! {
+            struct Shape;
+        };
+        let err = form_enum(input).unwrap_err();
+        assert_eq!(err.to_string(), "FormEnum can only be derived for enums");
+    }
+
+    #[test]
+    fn duplicate_values_are_rejected() {
+        let input: DeriveInput = parse_quote! {
+            enum Size {
+                Small,
+                #[form(value = "Small")]
+                Tiny,
+            }
+        };
+        let err = form_enum(input).unwrap_err();
+        assert_eq!(err.to_string(), "FormEnum values must be unique");
+    }
+
+    #[test]
+    fn unknown_attributes_are_rejected() {
+        let input: DeriveInput = parse_quote! {
+            enum Size {
+                #[form(name = "small")]
+                Small,
+            }
+        };
+        let err = form_enum(input).unwrap_err();
+        assert_eq!(err.to_string(), "expected `value` or `label`");
+    }
+}
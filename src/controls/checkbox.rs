@@ -1,5 +1,6 @@
 use super::{
-    BuilderCxFn, BuilderFn, ControlBuilder, ControlData, ControlRenderData, ValidationState,
+    BuilderCxFn, BuilderFn, ControlBuilder, ControlData, ControlRenderData, ValidatedControlData,
+    ValidationState,
 };
 use crate::{form::FormToolData, form_builder::FormBuilder, styles::FormStyle};
 use leptos::{prelude::*, reactive::wrappers::write::SignalSetter};
@@ -20,11 +21,12 @@ impl<FD: FormToolData> ControlData<FD> for CheckboxData {
         control: ControlRenderData<FS, Self>,
         value_getter: Signal<Self::ReturnType>,
         value_setter: SignalSetter<Self::ReturnType>,
-        _validation_state: Signal<ValidationState>,
+        validation_state: Signal<ValidationState>,
     ) -> AnyView {
-        fs.checkbox(control, value_getter, value_setter)
+        fs.checkbox(control, value_getter, value_setter, validation_state)
     }
 }
+impl<FD: FormToolData> ValidatedControlData<FD> for CheckboxData {}
 
 impl<FD: FormToolData> FormBuilder<FD> {
     /// Builds a checkbox and adds it to the form.
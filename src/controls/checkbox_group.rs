@@ -0,0 +1,133 @@
+use super::{
+    BuilderCxFn, BuilderFn, ControlBuilder, ControlData, ControlRenderData, OptionLayout,
+    ValidatedControlData, ValidationState,
+};
+use crate::{form::FormToolData, form_builder::FormBuilder, styles::FormStyle};
+use leptos::{
+    prelude::{AnyView, RwSignal, Signal},
+    reactive::wrappers::write::SignalSetter,
+};
+
+/// Data used for the checkbox group control.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct CheckboxGroupData {
+    pub name: String,
+    pub label: Option<String>,
+    /// The options for the checkbox group.
+    ///
+    /// The first value is the string to display, the second is the value.
+    pub options: Vec<(String, String)>,
+    /// How the checkboxes are laid out.
+    pub layout: OptionLayout,
+}
+
+impl<FD: FormToolData> ControlData<FD> for CheckboxGroupData {
+    type ReturnType = Vec<String>;
+
+    fn render_control<FS: FormStyle>(
+        fs: &FS,
+        _fd: RwSignal<FD>,
+        control: ControlRenderData<FS, Self>,
+        value_getter: Signal<Self::ReturnType>,
+        value_setter: SignalSetter<Self::ReturnType>,
+        validation_state: Signal<ValidationState>,
+    ) -> AnyView {
+        fs.checkbox_group(control, value_getter, value_setter, validation_state)
+    }
+}
+impl<FD: FormToolData> ValidatedControlData<FD> for CheckboxGroupData {}
+
+impl<FD: FormToolData> FormBuilder<FD> {
+    /// Builds a checkbox group control and adds it to the form.
+    ///
+    /// Unlike [`radio_buttons`](Self::radio_buttons), any number of options
+    /// can be checked.
+    pub fn checkbox_group<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderFn<ControlBuilder<FD, CheckboxGroupData, FDT>>,
+    ) -> Self {
+        self.new_control(builder)
+    }
+
+    /// Builds a checkbox group control using the form's context and adds it
+    /// to the form.
+    pub fn checkbox_group_cx<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderCxFn<ControlBuilder<FD, CheckboxGroupData, FDT>, FD::Context>,
+    ) -> Self {
+        self.new_control_cx(builder)
+    }
+}
+
+impl<FD: FormToolData, FDT> ControlBuilder<FD, CheckboxGroupData, FDT> {
+    /// Sets the name of the checkbox inputs.
+    ///
+    /// This is used for the html element's "name" attribute.
+    /// In forms, the name attribute is the key that the data is sent
+    /// with.
+    pub fn named(mut self, control_name: impl ToString) -> Self {
+        self.data.name = control_name.to_string();
+        self
+    }
+
+    /// Sets the label for the checkbox group.
+    pub fn labeled(mut self, label: impl ToString) -> Self {
+        self.data.label = Some(label.to_string());
+        self
+    }
+
+    /// Lays the checkboxes out on one line.
+    pub fn inline(mut self) -> Self {
+        self.data.layout = OptionLayout::Inline;
+        self
+    }
+
+    /// Lays the checkboxes out one above the other.
+    ///
+    /// This is the default.
+    pub fn stacked(mut self) -> Self {
+        self.data.layout = OptionLayout::Stacked;
+        self
+    }
+
+    /// Adds the option to the checkbox group.
+    pub fn with_option(mut self, option: impl ToString) -> Self {
+        self.data
+            .options
+            .push((option.to_string(), option.to_string()));
+        self
+    }
+
+    /// Adds the option to the checkbox group, specifying a different
+    /// value than what is displayed.
+    pub fn with_option_valued(mut self, display: impl ToString, value: impl ToString) -> Self {
+        self.data
+            .options
+            .push((display.to_string(), value.to_string()));
+        self
+    }
+
+    /// Adds all the options in the provided iterator to the checkbox group.
+    pub fn with_options(mut self, options: impl Iterator<Item = impl ToString>) -> Self {
+        for option in options {
+            self.data
+                .options
+                .push((option.to_string(), option.to_string()));
+        }
+        self
+    }
+
+    /// Adds all the (display_string, value) pairs in the provided iterator
+    /// to the checkbox group.
+    pub fn with_options_valued(
+        mut self,
+        options: impl Iterator<Item = (impl ToString, impl ToString)>,
+    ) -> Self {
+        for option in options {
+            self.data
+                .options
+                .push((option.0.to_string(), option.1.to_string()));
+        }
+        self
+    }
+}
@@ -0,0 +1,292 @@
+use super::{
+    BuilderCxFn, BuilderFn, ControlBuilder, ControlData, ControlRenderData, ValidatedControlData,
+    ValidationState,
+};
+use crate::{form::FormToolData, form_builder::FormBuilder, styles::FormStyle};
+use leptos::{
+    prelude::{
+        set_timeout, AnyView, Effect, Get, GetValue, RwSignal, Set, SetValue, Signal, StoredValue,
+        WithUntracked,
+    },
+    reactive::wrappers::write::SignalSetter,
+    task,
+};
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+/// Gets the (display_string, value) suggestions for what the user typed.
+type SuggestionFn =
+    dyn Fn(String) -> Pin<Box<dyn Future<Output = Vec<(String, String)>>>> + Send + Sync + 'static;
+
+/// Data used for building the combobox control.
+#[derive(Clone)]
+pub struct ComboboxBuildData {
+    pub name: String,
+    pub label: Option<String>,
+    pub placeholder: Option<String>,
+    /// Whether the value must be picked from the suggestions, instead of
+    /// being free text.
+    pub must_pick: bool,
+    /// How long to wait after the user stops typing before getting
+    /// suggestions.
+    pub debounce: Duration,
+    /// The function that gets the suggestions.
+    ///
+    /// This is just a temp value for building, and should not be used
+    /// directly
+    suggestions: Option<Arc<SuggestionFn>>,
+    /// Whether the last value that the user entered was one of the
+    /// suggestions, for the `must_pick` validation.
+    ///
+    /// This is just a temp value for building, and should not be used
+    /// directly
+    picked_suggestion: Arc<AtomicBool>,
+}
+impl Default for ComboboxBuildData {
+    fn default() -> Self {
+        ComboboxBuildData {
+            name: String::new(),
+            label: None,
+            placeholder: None,
+            must_pick: false,
+            debounce: Duration::from_millis(250),
+            suggestions: None,
+            picked_suggestion: Arc::new(AtomicBool::new(true)),
+        }
+    }
+}
+
+/// Data used for the combobox control.
+#[derive(Clone)]
+pub struct ComboboxData {
+    pub name: String,
+    pub label: Option<String>,
+    pub placeholder: Option<String>,
+    /// Whether the value must be picked from the suggestions, instead of
+    /// being free text.
+    pub must_pick: bool,
+    /// Sets the text that suggestions are got for.
+    ///
+    /// The style should set this as the user types.
+    pub query: SignalSetter<String>,
+    /// The suggestions for the current query.
+    ///
+    /// The first value is the string to display, the second is the value.
+    pub suggestions: Signal<Vec<(String, String)>>,
+    /// Whether suggestions are being loaded.
+    pub loading: Signal<bool>,
+}
+
+impl<FD: FormToolData> ControlData<FD> for ComboboxBuildData {
+    type ReturnType = String;
+
+    fn render_control<FS: FormStyle>(
+        fs: &FS,
+        _fd: RwSignal<FD>,
+        control: ControlRenderData<FS, Self>,
+        value_getter: Signal<Self::ReturnType>,
+        value_setter: SignalSetter<Self::ReturnType>,
+        validation_state: Signal<ValidationState>,
+    ) -> AnyView {
+        let query = RwSignal::new(String::new());
+        let suggestions = RwSignal::new(Vec::new());
+        let loading = RwSignal::new(false);
+
+        if let Some(source) = control.data.suggestions.clone() {
+            let debounce = control.data.debounce;
+            // the latest query, so outdated requests can be dropped
+            let latest = StoredValue::new(0_usize);
+            Effect::watch(
+                move || query.get(),
+                move |query, _, _| {
+                    let version = latest.get_value() + 1;
+                    latest.set_value(version);
+
+                    if query.is_empty() {
+                        suggestions.set(Vec::new());
+                        loading.set(false);
+                        return;
+                    }
+
+                    let query = query.clone();
+                    let source = source.clone();
+                    set_timeout(
+                        move || {
+                            if latest.get_value() != version {
+                                return;
+                            }
+                            loading.set(true);
+                            task::spawn_local(async move {
+                                let result = source(query).await;
+                                if latest.get_value() == version {
+                                    suggestions.set(result);
+                                    loading.set(false);
+                                }
+                            });
+                        },
+                        debounce,
+                    );
+                },
+                false,
+            );
+        }
+
+        // the style may set any text, so check that it was suggested here
+        let value_setter = match control.data.must_pick {
+            true => {
+                let picked_suggestion = control.data.picked_suggestion.clone();
+                SignalSetter::map(move |value: String| {
+                    let suggested = value.is_empty()
+                        || suggestions.with_untracked(|s| s.iter().any(|(_, v)| *v == value));
+                    picked_suggestion.store(suggested, Ordering::Relaxed);
+                    value_setter.set(value);
+                })
+            }
+            false => value_setter,
+        };
+
+        let new_control = ControlRenderData {
+            styles: control.styles.clone(),
+            data: ComboboxData {
+                name: control.data.name.clone(),
+                label: control.data.label.clone(),
+                placeholder: control.data.placeholder.clone(),
+                must_pick: control.data.must_pick,
+                query: query.write_only().into(),
+                suggestions: suggestions.into(),
+                loading: loading.into(),
+            },
+        };
+
+        fs.combobox(new_control, value_getter, value_setter, validation_state)
+    }
+}
+impl<FD: FormToolData> ValidatedControlData<FD> for ComboboxBuildData {}
+
+impl<FD: FormToolData> FormBuilder<FD> {
+    /// Builds a combobox control and adds it to the form.
+    ///
+    /// A combobox is a text input that suggests values as the user types.
+    pub fn combobox<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderFn<ControlBuilder<FD, ComboboxBuildData, FDT>>,
+    ) -> Self {
+        self.new_control(builder)
+    }
+
+    /// Builds a combobox control using the form's context and adds it to
+    /// the form.
+    pub fn combobox_cx<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderCxFn<ControlBuilder<FD, ComboboxBuildData, FDT>, FD::Context>,
+    ) -> Self {
+        self.new_control_cx(builder)
+    }
+}
+
+impl<FD: FormToolData, FDT> ControlBuilder<FD, ComboboxBuildData, FDT> {
+    /// Sets the name of the combobox.
+    ///
+    /// This is used for the html element's "name" attribute.
+    /// In forms, the name attribute is the key that the data is sent
+    /// with.
+    pub fn named(mut self, control_name: impl ToString) -> Self {
+        self.data.name = control_name.to_string();
+        self
+    }
+
+    /// Sets the label for the combobox.
+    pub fn labeled(mut self, label: impl ToString) -> Self {
+        self.data.label = Some(label.to_string());
+        self
+    }
+
+    /// Sets the placeholder for the combobox.
+    pub fn placeholder(mut self, placeholder: impl ToString) -> Self {
+        self.data.placeholder = Some(placeholder.to_string());
+        self
+    }
+
+    /// Requires the value to be picked from the suggestions.
+    ///
+    /// Without this, any text that the user types is used as the value.
+    /// With it, validation fails when the user enters a value that wasn't
+    /// suggested. The suggestions only exist in the browser, so the
+    /// [`FormValidator`](crate::FormValidator) can't check this.
+    pub fn must_pick(mut self) -> Self {
+        self.data.must_pick = true;
+        let picked_suggestion = self.data.picked_suggestion.clone();
+        self.field_validations.push(Arc::new(
+            move |_fd: &FD, _field: &FDT| match picked_suggestion.load(Ordering::Relaxed) {
+                true => Ok(()),
+                false => Err(String::from("Please pick one of the suggestions")),
+            },
+        ));
+        self
+    }
+
+    /// Sets how long to wait after the user stops typing before getting
+    /// suggestions.
+    ///
+    /// This defaults to 250ms.
+    pub fn debounce(mut self, debounce: Duration) -> Self {
+        self.data.debounce = debounce;
+        self
+    }
+
+    /// Sets the function that gets the (display_string, value) suggestions
+    /// for what the user typed.
+    ///
+    /// This can be async, like calling a server function.
+    /// This will overwrite any pervious suggestions setting.
+    pub fn with_suggestions<Fut>(
+        mut self,
+        suggestions: impl Fn(String) -> Fut + Send + Sync + 'static,
+    ) -> Self
+    where
+        Fut: Future<Output = Vec<(String, String)>> + 'static,
+    {
+        self.data.suggestions = Some(Arc::new(move |query| Box::pin(suggestions(query))));
+        self
+    }
+
+    /// Suggests the options from the provided iterator that contain what
+    /// the user typed, ignoring case.
+    ///
+    /// This will overwrite any pervious suggestions setting.
+    pub fn with_options(self, options: impl Iterator<Item = impl ToString>) -> Self {
+        self.with_options_valued(options.map(|v| (v.to_string(), v.to_string())))
+    }
+
+    /// Suggests the (display_string, value) pairs from the provided
+    /// iterator whose display string contains what the user typed, ignoring
+    /// case.
+    ///
+    /// This will overwrite any pervious suggestions setting.
+    pub fn with_options_valued(
+        self,
+        options: impl Iterator<Item = (impl ToString, impl ToString)>,
+    ) -> Self {
+        let options: Arc<Vec<(String, String)>> = Arc::new(
+            options
+                .map(|(d, v)| (d.to_string(), v.to_string()))
+                .collect(),
+        );
+        self.with_suggestions(move |query| {
+            let query = query.to_lowercase();
+            let matches = options
+                .iter()
+                .filter(|(display, _)| display.to_lowercase().contains(&query))
+                .cloned()
+                .collect();
+            async move { matches }
+        })
+    }
+}
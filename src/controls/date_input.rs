@@ -0,0 +1,610 @@
+use super::{
+    BuilderCxFn, BuilderFn, ControlBuilder, ControlData, ControlRenderData, ValidatedControlData,
+    ValidationState,
+};
+use crate::{form::FormToolData, form_builder::FormBuilder, styles::FormStyle};
+use leptos::{
+    prelude::{AnyView, RwSignal, Signal},
+    reactive::wrappers::write::SignalSetter,
+};
+
+/// The kind of value that a date input picks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum DateKind {
+    /// A date, as `YYYY-MM-DD`.
+    #[default]
+    Date,
+    /// A time of day, as `HH:MM` or `HH:MM:SS`.
+    Time,
+    /// A local date and time, as `YYYY-MM-DDTHH:MM`.
+    DateTime,
+    /// A year and month, as `YYYY-MM`.
+    Month,
+}
+impl DateKind {
+    /// Gets the html input type for this kind.
+    pub fn input_type(&self) -> &'static str {
+        match self {
+            DateKind::Date => "date",
+            DateKind::Time => "time",
+            DateKind::DateTime => "datetime-local",
+            DateKind::Month => "month",
+        }
+    }
+
+    /// Gets a human readable name of this kind, for error messages.
+    pub fn describe(&self) -> &'static str {
+        match self {
+            DateKind::Date => "date",
+            DateKind::Time => "time",
+            DateKind::DateTime => "date and time",
+            DateKind::Month => "month",
+        }
+    }
+}
+
+/// A value that can be picked with a date input.
+///
+/// This converts between the value and the strings that the browser uses
+/// for the given [`DateKind`].
+///
+/// This is implemented for [`String`], which is kept as is, and for
+/// [`Option`]s of other [`FormDate`]s, where an empty input is `None`.
+/// With the `chrono` feature, it is implemented for `NaiveDate`,
+/// `NaiveTime` and `NaiveDateTime`. With the `time` feature, it is
+/// implemented for `Date`, `Time` and `PrimitiveDateTime`.
+/// Dates can be used for [`DateKind::Month`], in which case the first day
+/// of the month is used.
+pub trait FormDate: Sized {
+    /// Parses the browser's value of the given kind.
+    fn parse_input(value: &str, kind: DateKind) -> Result<Self, String>;
+
+    /// Formats this value for the given kind.
+    fn to_input(&self, kind: DateKind) -> String;
+}
+
+impl FormDate for String {
+    fn parse_input(value: &str, _kind: DateKind) -> Result<Self, String> {
+        Ok(value.to_string())
+    }
+
+    fn to_input(&self, _kind: DateKind) -> String {
+        self.clone()
+    }
+}
+
+impl<T: FormDate> FormDate for Option<T> {
+    fn parse_input(value: &str, kind: DateKind) -> Result<Self, String> {
+        if value.is_empty() {
+            return Ok(None);
+        }
+        T::parse_input(value, kind).map(Some)
+    }
+
+    fn to_input(&self, kind: DateKind) -> String {
+        self.as_ref().map(|v| v.to_input(kind)).unwrap_or_default()
+    }
+}
+
+/// Creates the error for a value that could not be parsed.
+#[cfg(any(feature = "chrono", feature = "time"))]
+fn invalid(kind: DateKind) -> String {
+    format!("Invalid {}", kind.describe())
+}
+
+/// Creates the error for a type that can't be picked with the given kind.
+#[cfg(any(feature = "chrono", feature = "time"))]
+fn unsupported(kind: DateKind) -> String {
+    format!("A {} can't be picked with this input", kind.describe())
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_impls {
+    use super::{invalid, unsupported, DateKind, FormDate};
+    use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+
+    impl FormDate for NaiveDate {
+        fn parse_input(value: &str, kind: DateKind) -> Result<Self, String> {
+            match kind {
+                DateKind::Date => NaiveDate::parse_from_str(value, "%Y-%m-%d"),
+                DateKind::Month => NaiveDate::parse_from_str(&format!("{value}-01"), "%Y-%m-%d"),
+                _ => return Err(unsupported(kind)),
+            }
+            .map_err(|_| invalid(kind))
+        }
+
+        fn to_input(&self, kind: DateKind) -> String {
+            match kind {
+                DateKind::Month => self.format("%Y-%m").to_string(),
+                _ => self.format("%Y-%m-%d").to_string(),
+            }
+        }
+    }
+
+    impl FormDate for NaiveTime {
+        fn parse_input(value: &str, kind: DateKind) -> Result<Self, String> {
+            if kind != DateKind::Time {
+                return Err(unsupported(kind));
+            }
+            NaiveTime::parse_from_str(value, "%H:%M:%S%.f")
+                .or_else(|_| NaiveTime::parse_from_str(value, "%H:%M"))
+                .map_err(|_| invalid(kind))
+        }
+
+        fn to_input(&self, _kind: DateKind) -> String {
+            if self.second() == 0 && self.nanosecond() == 0 {
+                self.format("%H:%M").to_string()
+            } else {
+                self.format("%H:%M:%S%.f").to_string()
+            }
+        }
+    }
+
+    impl FormDate for NaiveDateTime {
+        fn parse_input(value: &str, kind: DateKind) -> Result<Self, String> {
+            if kind != DateKind::DateTime {
+                return Err(unsupported(kind));
+            }
+            NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f")
+                .or_else(|_| NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M"))
+                .map_err(|_| invalid(kind))
+        }
+
+        fn to_input(&self, kind: DateKind) -> String {
+            format!(
+                "{}T{}",
+                self.date().to_input(DateKind::Date),
+                self.time().to_input(kind)
+            )
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+mod time_impls {
+    use super::{invalid, unsupported, DateKind, FormDate};
+    use time::{
+        format_description::BorrowedFormatItem, macros::format_description, Date,
+        PrimitiveDateTime, Time,
+    };
+
+    const DATE: &[BorrowedFormatItem] = format_description!("[year]-[month]-[day]");
+    const MONTH: &[BorrowedFormatItem] = format_description!("[year]-[month]");
+    const TIME_MINUTES: &[BorrowedFormatItem] = format_description!("[hour]:[minute]");
+    const TIME_SECONDS: &[BorrowedFormatItem] = format_description!("[hour]:[minute]:[second]");
+    const TIME_SUBSECONDS: &[BorrowedFormatItem] =
+        format_description!("[hour]:[minute]:[second].[subsecond]");
+
+    impl FormDate for Date {
+        fn parse_input(value: &str, kind: DateKind) -> Result<Self, String> {
+            match kind {
+                DateKind::Date => Date::parse(value, DATE),
+                DateKind::Month => Date::parse(&format!("{value}-01"), DATE),
+                _ => return Err(unsupported(kind)),
+            }
+            .map_err(|_| invalid(kind))
+        }
+
+        fn to_input(&self, kind: DateKind) -> String {
+            let format = match kind {
+                DateKind::Month => MONTH,
+                _ => DATE,
+            };
+            self.format(format).unwrap_or_default()
+        }
+    }
+
+    impl FormDate for Time {
+        fn parse_input(value: &str, kind: DateKind) -> Result<Self, String> {
+            if kind != DateKind::Time {
+                return Err(unsupported(kind));
+            }
+            Time::parse(value, TIME_SUBSECONDS)
+                .or_else(|_| Time::parse(value, TIME_SECONDS))
+                .or_else(|_| Time::parse(value, TIME_MINUTES))
+                .map_err(|_| invalid(kind))
+        }
+
+        fn to_input(&self, _kind: DateKind) -> String {
+            let format = match (self.second(), self.nanosecond()) {
+                (0, 0) => TIME_MINUTES,
+                (_, 0) => TIME_SECONDS,
+                _ => TIME_SUBSECONDS,
+            };
+            self.format(format).unwrap_or_default()
+        }
+    }
+
+    impl FormDate for PrimitiveDateTime {
+        fn parse_input(value: &str, kind: DateKind) -> Result<Self, String> {
+            if kind != DateKind::DateTime {
+                return Err(unsupported(kind));
+            }
+            let (date, time) = value.split_once('T').ok_or_else(|| invalid(kind))?;
+            let date = Date::parse_input(date, DateKind::Date).map_err(|_| invalid(kind))?;
+            let time = Time::parse_input(time, DateKind::Time).map_err(|_| invalid(kind))?;
+            Ok(PrimitiveDateTime::new(date, time))
+        }
+
+        fn to_input(&self, _kind: DateKind) -> String {
+            format!(
+                "{}T{}",
+                self.date().to_input(DateKind::Date),
+                self.time().to_input(DateKind::Time)
+            )
+        }
+    }
+}
+
+/// Data used for the date input control.
+///
+/// This is used for the date, time, datetime and month inputs, as set by
+/// the [`DateKind`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct DateInputData {
+    pub name: String,
+    pub label: Option<String>,
+    pub kind: DateKind,
+    /// The earliest value that can be picked, in the browser's format.
+    pub min: Option<String>,
+    /// The latest value that can be picked, in the browser's format.
+    pub max: Option<String>,
+    /// The step of the input, in the html "step" attribute's format.
+    pub step: Option<String>,
+}
+
+impl<FD: FormToolData> ControlData<FD> for DateInputData {
+    type ReturnType = String;
+
+    fn render_control<FS: FormStyle>(
+        fs: &FS,
+        _fd: RwSignal<FD>,
+        control: ControlRenderData<FS, Self>,
+        value_getter: Signal<Self::ReturnType>,
+        value_setter: SignalSetter<Self::ReturnType>,
+        validation_state: Signal<ValidationState>,
+    ) -> AnyView {
+        fs.date_input(control, value_getter, value_setter, validation_state)
+    }
+}
+impl<FD: FormToolData> ValidatedControlData<FD> for DateInputData {}
+
+/// Sets the kind of a date input before running the user's builder.
+fn with_kind<FD: FormToolData, FDT>(
+    kind: DateKind,
+    builder: impl BuilderFn<ControlBuilder<FD, DateInputData, FDT>>,
+) -> impl BuilderFn<ControlBuilder<FD, DateInputData, FDT>> {
+    move |mut control: ControlBuilder<FD, DateInputData, FDT>| {
+        control.data.kind = kind;
+        builder(control)
+    }
+}
+
+/// Sets the kind of a date input before running the user's builder, using
+/// the form's context.
+fn with_kind_cx<FD: FormToolData, FDT>(
+    kind: DateKind,
+    builder: impl BuilderCxFn<ControlBuilder<FD, DateInputData, FDT>, FD::Context>,
+) -> impl BuilderCxFn<ControlBuilder<FD, DateInputData, FDT>, FD::Context> {
+    move |mut control: ControlBuilder<FD, DateInputData, FDT>, cx| {
+        control.data.kind = kind;
+        builder(control, cx)
+    }
+}
+
+impl<FD: FormToolData> FormBuilder<FD> {
+    /// Builds a date input control and adds it to the form.
+    pub fn date_input<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderFn<ControlBuilder<FD, DateInputData, FDT>>,
+    ) -> Self {
+        self.new_control(with_kind(DateKind::Date, builder))
+    }
+
+    /// Builds a date input control using the form's context and adds it to
+    /// the form.
+    pub fn date_input_cx<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderCxFn<ControlBuilder<FD, DateInputData, FDT>, FD::Context>,
+    ) -> Self {
+        self.new_control_cx(with_kind_cx(DateKind::Date, builder))
+    }
+
+    /// Builds a time input control and adds it to the form.
+    pub fn time_input<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderFn<ControlBuilder<FD, DateInputData, FDT>>,
+    ) -> Self {
+        self.new_control(with_kind(DateKind::Time, builder))
+    }
+
+    /// Builds a time input control using the form's context and adds it to
+    /// the form.
+    pub fn time_input_cx<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderCxFn<ControlBuilder<FD, DateInputData, FDT>, FD::Context>,
+    ) -> Self {
+        self.new_control_cx(with_kind_cx(DateKind::Time, builder))
+    }
+
+    /// Builds a local date and time input control and adds it to the form.
+    pub fn datetime_input<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderFn<ControlBuilder<FD, DateInputData, FDT>>,
+    ) -> Self {
+        self.new_control(with_kind(DateKind::DateTime, builder))
+    }
+
+    /// Builds a local date and time input control using the form's context
+    /// and adds it to the form.
+    pub fn datetime_input_cx<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderCxFn<ControlBuilder<FD, DateInputData, FDT>, FD::Context>,
+    ) -> Self {
+        self.new_control_cx(with_kind_cx(DateKind::DateTime, builder))
+    }
+
+    /// Builds a month input control and adds it to the form.
+    pub fn month_input<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderFn<ControlBuilder<FD, DateInputData, FDT>>,
+    ) -> Self {
+        self.new_control(with_kind(DateKind::Month, builder))
+    }
+
+    /// Builds a month input control using the form's context and adds it to
+    /// the form.
+    pub fn month_input_cx<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderCxFn<ControlBuilder<FD, DateInputData, FDT>, FD::Context>,
+    ) -> Self {
+        self.new_control_cx(with_kind_cx(DateKind::Month, builder))
+    }
+}
+
+impl<FD: FormToolData, FDT> ControlBuilder<FD, DateInputData, FDT> {
+    /// Sets the name of the date input.
+    ///
+    /// This is used for the html element's "name" attribute.
+    /// In forms, the name attribute is the key that the data is sent
+    /// with.
+    pub fn named(mut self, control_name: impl ToString) -> Self {
+        self.data.name = control_name.to_string();
+        self
+    }
+
+    /// Sets the label for the date input.
+    pub fn labeled(mut self, label: impl ToString) -> Self {
+        self.data.label = Some(label.to_string());
+        self
+    }
+
+    /// Sets the earliest value that can be picked.
+    ///
+    /// This only limits the browser's picker; to also check the value, use
+    /// a validation like [`ValidationBuilder::after`](crate::ValidationBuilder::after),
+    /// or [`after_if_some`](crate::ValidationBuilder::after_if_some) for an
+    /// `Option`.
+    pub fn min(mut self, min: impl FormDate) -> Self {
+        self.data.min = Some(min.to_input(self.data.kind));
+        self
+    }
+
+    /// Sets the latest value that can be picked.
+    ///
+    /// This only limits the browser's picker; to also check the value, use
+    /// a validation like [`ValidationBuilder::before`](crate::ValidationBuilder::before),
+    /// or [`before_if_some`](crate::ValidationBuilder::before_if_some) for an
+    /// `Option`.
+    pub fn max(mut self, max: impl FormDate) -> Self {
+        self.data.max = Some(max.to_input(self.data.kind));
+        self
+    }
+
+    /// Sets the step of the input, in the html "step" attribute's format.
+    ///
+    /// For example, a time input with a step of `1` allows picking seconds.
+    pub fn step(mut self, step: impl ToString) -> Self {
+        self.data.step = Some(step.to_string());
+        self
+    }
+}
+
+impl<FD: FormToolData, FDT: FormDate + 'static> ControlBuilder<FD, DateInputData, FDT> {
+    /// Sets the parse functions to use the [`FormDate`] trait for parsing
+    /// and unparsing.
+    ///
+    /// The parse and unparse functions define how to turn what the user
+    /// picks in the form into what is stored in the form data struct and
+    /// vice versa.
+    pub fn parse_date(mut self) -> Self {
+        let kind = self.data.kind;
+        self.parse_fn = Some(Box::new(move |value: String| {
+            FDT::parse_input(&value, kind)
+        }));
+        self.unparse_fn = Some(Box::new(move |field: FDT| field.to_input(kind)));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strings_are_kept_as_is() {
+        assert_eq!(
+            String::parse_input("2024-02-30", DateKind::Date),
+            Ok(String::from("2024-02-30"))
+        );
+        assert_eq!(String::from("12:30").to_input(DateKind::Time), "12:30");
+    }
+
+    #[test]
+    fn empty_options_are_none() {
+        assert_eq!(Option::<String>::parse_input("", DateKind::Date), Ok(None));
+        assert_eq!(None::<String>.to_input(DateKind::Date), "");
+    }
+
+    #[cfg(feature = "chrono")]
+    mod chrono_tests {
+        use super::super::*;
+        use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+        fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+            NaiveDate::from_ymd_opt(y, m, d).unwrap()
+        }
+
+        #[test]
+        fn dates_round_trip() {
+            let value = NaiveDate::parse_input("2024-02-29", DateKind::Date);
+            assert_eq!(value, Ok(date(2024, 2, 29)));
+            assert_eq!(date(2024, 2, 29).to_input(DateKind::Date), "2024-02-29");
+            assert_eq!(
+                NaiveDate::parse_input("2023-02-29", DateKind::Date),
+                Err(String::from("Invalid date"))
+            );
+        }
+
+        #[test]
+        fn months_use_the_first_day() {
+            assert_eq!(
+                NaiveDate::parse_input("2024-07", DateKind::Month),
+                Ok(date(2024, 7, 1))
+            );
+            assert_eq!(date(2024, 7, 15).to_input(DateKind::Month), "2024-07");
+            assert_eq!(
+                NaiveDate::parse_input("2024-13", DateKind::Month),
+                Err(String::from("Invalid month"))
+            );
+        }
+
+        #[test]
+        fn times_take_minutes_or_seconds() {
+            let minutes = NaiveTime::from_hms_opt(9, 5, 0).unwrap();
+            let seconds = NaiveTime::from_hms_opt(9, 5, 30).unwrap();
+            assert_eq!(NaiveTime::parse_input("09:05", DateKind::Time), Ok(minutes));
+            assert_eq!(
+                NaiveTime::parse_input("09:05:30", DateKind::Time),
+                Ok(seconds)
+            );
+            // seconds are only shown when there are some
+            assert_eq!(minutes.to_input(DateKind::Time), "09:05");
+            assert_eq!(seconds.to_input(DateKind::Time), "09:05:30");
+            assert_eq!(
+                NaiveTime::parse_input("9am", DateKind::Time),
+                Err(String::from("Invalid time"))
+            );
+        }
+
+        #[test]
+        fn date_times_use_the_t_separator() {
+            let value = date(2024, 2, 29).and_hms_opt(18, 45, 0).unwrap();
+            assert_eq!(
+                NaiveDateTime::parse_input("2024-02-29T18:45", DateKind::DateTime),
+                Ok(value)
+            );
+            assert_eq!(value.to_input(DateKind::DateTime), "2024-02-29T18:45");
+            assert_eq!(
+                NaiveDateTime::parse_input("2024-02-29T18:45:10", DateKind::DateTime),
+                Ok(date(2024, 2, 29).and_hms_opt(18, 45, 10).unwrap())
+            );
+            assert_eq!(
+                NaiveDateTime::parse_input("2024-02-29 18:45", DateKind::DateTime),
+                Err(String::from("Invalid date and time"))
+            );
+        }
+
+        #[test]
+        fn other_kinds_are_unsupported() {
+            assert_eq!(
+                NaiveTime::parse_input("2024-02-29", DateKind::Date),
+                Err(String::from("A date can't be picked with this input"))
+            );
+            assert!(NaiveDate::parse_input("09:05", DateKind::Time).is_err());
+            assert!(NaiveDateTime::parse_input("2024-02-29", DateKind::Date).is_err());
+        }
+    }
+
+    #[cfg(feature = "time")]
+    mod time_tests {
+        use super::super::*;
+        use time::{macros::date, macros::datetime, macros::time, Date, PrimitiveDateTime, Time};
+
+        #[test]
+        fn dates_round_trip() {
+            assert_eq!(
+                Date::parse_input("2024-02-29", DateKind::Date),
+                Ok(date!(2024 - 02 - 29))
+            );
+            assert_eq!(date!(2024 - 02 - 29).to_input(DateKind::Date), "2024-02-29");
+            assert_eq!(
+                Date::parse_input("2023-02-29", DateKind::Date),
+                Err(String::from("Invalid date"))
+            );
+        }
+
+        #[test]
+        fn months_use_the_first_day() {
+            assert_eq!(
+                Date::parse_input("2024-07", DateKind::Month),
+                Ok(date!(2024 - 07 - 01))
+            );
+            assert_eq!(date!(2024 - 07 - 15).to_input(DateKind::Month), "2024-07");
+            assert_eq!(
+                Date::parse_input("2024-13", DateKind::Month),
+                Err(String::from("Invalid month"))
+            );
+        }
+
+        #[test]
+        fn times_take_minutes_or_seconds() {
+            assert_eq!(Time::parse_input("09:05", DateKind::Time), Ok(time!(9:05)));
+            assert_eq!(
+                Time::parse_input("09:05:30", DateKind::Time),
+                Ok(time!(9:05:30))
+            );
+            assert_eq!(
+                Time::parse_input("09:05:30.5", DateKind::Time),
+                Ok(time!(9:05:30.5))
+            );
+            // seconds are only shown when there are some
+            assert_eq!(time!(9:05).to_input(DateKind::Time), "09:05");
+            assert_eq!(time!(9:05:30).to_input(DateKind::Time), "09:05:30");
+            assert_eq!(
+                Time::parse_input("9am", DateKind::Time),
+                Err(String::from("Invalid time"))
+            );
+        }
+
+        #[test]
+        fn date_times_use_the_t_separator() {
+            assert_eq!(
+                PrimitiveDateTime::parse_input("2024-02-29T18:45", DateKind::DateTime),
+                Ok(datetime!(2024-02-29 18:45))
+            );
+            assert_eq!(
+                datetime!(2024-02-29 18:45).to_input(DateKind::DateTime),
+                "2024-02-29T18:45"
+            );
+            assert_eq!(
+                PrimitiveDateTime::parse_input("2024-02-29T18:45:10", DateKind::DateTime),
+                Ok(datetime!(2024-02-29 18:45:10))
+            );
+            assert_eq!(
+                PrimitiveDateTime::parse_input("2024-02-29 18:45", DateKind::DateTime),
+                Err(String::from("Invalid date and time"))
+            );
+        }
+
+        #[test]
+        fn other_kinds_are_unsupported() {
+            assert_eq!(
+                Time::parse_input("2024-02-29", DateKind::Date),
+                Err(String::from("A date can't be picked with this input"))
+            );
+            assert!(Date::parse_input("09:05", DateKind::Time).is_err());
+            assert!(PrimitiveDateTime::parse_input("2024-02-29", DateKind::Date).is_err());
+        }
+    }
+}
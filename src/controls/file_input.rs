@@ -0,0 +1,158 @@
+use super::{
+    BuilderCxFn, BuilderFn, ControlBuilder, ControlData, ControlRenderData, ValidatedControlData,
+    ValidationState,
+};
+use crate::{form::FormToolData, form_builder::FormBuilder, styles::FormStyle};
+use leptos::{
+    prelude::{AnyView, RwSignal, Signal},
+    reactive::wrappers::write::SignalSetter,
+};
+use send_wrapper::SendWrapper;
+use serde::{Deserialize, Serialize};
+use web_sys::{File, FileList};
+
+/// A handle to a file that the user picked in a file input.
+///
+/// This holds the file's metadata, and the browser's [`File`] on the client.
+/// The file itself is not serialized, only the metadata.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct UploadedFile {
+    /// The name of the file.
+    pub name: String,
+    /// The size of the file in bytes.
+    pub size: u64,
+    /// The MIME type of the file, if the browser knows it.
+    pub mime_type: String,
+    #[serde(skip)]
+    file: Option<SendWrapper<File>>,
+}
+impl UploadedFile {
+    /// Gets the browser's [`File`] for reading the file on the client.
+    ///
+    /// This is `None` if the handle was not created in the browser.
+    pub fn file(&self) -> Option<&File> {
+        self.file.as_deref()
+    }
+
+    /// Collects all the files in the given [`FileList`].
+    pub fn from_file_list(files: &FileList) -> Vec<UploadedFile> {
+        (0..files.length())
+            .filter_map(|i| files.get(i))
+            .map(UploadedFile::from)
+            .collect()
+    }
+}
+impl From<File> for UploadedFile {
+    fn from(file: File) -> Self {
+        UploadedFile {
+            name: file.name(),
+            size: file.size() as u64,
+            mime_type: file.type_(),
+            file: Some(SendWrapper::new(file)),
+        }
+    }
+}
+impl PartialEq for UploadedFile {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.size == other.size && self.mime_type == other.mime_type
+    }
+}
+impl Eq for UploadedFile {}
+
+/// Data used for the file input control.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct FileInputData {
+    pub name: String,
+    pub label: Option<String>,
+    /// Whether the user can pick more than one file.
+    pub multiple: bool,
+    /// The file types that the input accepts, in the format of the html
+    /// "accept" attribute (ex. "image/*,.pdf").
+    pub accept: Option<String>,
+}
+
+impl<FD: FormToolData> ControlData<FD> for FileInputData {
+    type ReturnType = Vec<UploadedFile>;
+
+    fn render_control<FS: FormStyle>(
+        fs: &FS,
+        _fd: RwSignal<FD>,
+        control: ControlRenderData<FS, Self>,
+        value_getter: Signal<Self::ReturnType>,
+        value_setter: SignalSetter<Self::ReturnType>,
+        validation_state: Signal<ValidationState>,
+    ) -> AnyView {
+        fs.file_input(control, value_getter, value_setter, validation_state)
+    }
+}
+impl<FD: FormToolData> ValidatedControlData<FD> for FileInputData {}
+
+impl<FD: FormToolData> FormBuilder<FD> {
+    /// Builds a file input control and adds it to the form.
+    ///
+    /// To send the files to the server, build the form with
+    /// [`get_multipart_form`](crate::FormToolData::get_multipart_form).
+    pub fn file_input<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderFn<ControlBuilder<FD, FileInputData, FDT>>,
+    ) -> Self {
+        self.new_control(builder)
+    }
+
+    /// Builds a file input control using the form's context and adds it to
+    /// the form.
+    pub fn file_input_cx<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderCxFn<ControlBuilder<FD, FileInputData, FDT>, FD::Context>,
+    ) -> Self {
+        self.new_control_cx(builder)
+    }
+}
+
+impl<FD: FormToolData, FDT> ControlBuilder<FD, FileInputData, FDT> {
+    /// Sets the name of the file input.
+    ///
+    /// This is used for the html element's "name" attribute.
+    /// In forms, the name attribute is the key that the data is sent
+    /// with.
+    pub fn named(mut self, control_name: impl ToString) -> Self {
+        self.data.name = control_name.to_string();
+        self
+    }
+
+    /// Sets the label for the file input.
+    pub fn labeled(mut self, label: impl ToString) -> Self {
+        self.data.label = Some(label.to_string());
+        self
+    }
+
+    /// Allows the user to pick more than one file.
+    pub fn multiple(mut self) -> Self {
+        self.data.multiple = true;
+        self
+    }
+
+    /// Sets the file types that the input accepts.
+    ///
+    /// This uses the format of the html "accept" attribute
+    /// (ex. "image/*,.pdf").
+    pub fn accept(mut self, accept: impl ToString) -> Self {
+        self.data.accept = Some(accept.to_string());
+        self
+    }
+}
+
+impl<FD: FormToolData> ControlBuilder<FD, FileInputData, Option<UploadedFile>> {
+    /// Sets the parse functions for a field that holds a single file.
+    ///
+    /// The first picked file is used, or `None` if no file is picked.
+    pub fn parse_single(mut self) -> Self {
+        self.parse_fn = Some(Box::new(|files: Vec<UploadedFile>| {
+            Ok(files.into_iter().next())
+        }));
+        self.unparse_fn = Some(Box::new(|file: Option<UploadedFile>| {
+            file.into_iter().collect()
+        }));
+        self
+    }
+}
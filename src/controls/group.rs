@@ -10,12 +10,13 @@ impl<FD: FormToolData> FormBuilder<FD> {
     /// This creates a subsection of the form that controls can be added to
     /// like a normal form.
     pub fn group(mut self, builder: impl Fn(FormBuilder<FD>) -> FormBuilder<FD>) -> Self {
-        let mut group_builder = FormBuilder::new_group(self.cx.clone());
+        let mut group_builder = FormBuilder::new_group(self.cx.clone(), self.status.clone());
         group_builder = builder(group_builder);
 
         for validation in group_builder.validations {
             self.validations.push(validation);
         }
+        self.before_submit.append(&mut group_builder.before_submit);
 
         let render_fn = move |fs: Arc<FD::Style>, fd: RwSignal<FD>| {
             let (views, validation_cbs): (Vec<_>, Vec<_>) = group_builder
@@ -31,10 +32,10 @@ impl<FD: FormToolData> FormBuilder<FD> {
 
             let view = fs.group(render_data);
 
-            let validation_cb = move || {
+            let validation_cb = move |intent: Option<&str>| {
                 let mut success = true;
                 for validation in validation_cbs.iter().flatten() {
-                    if !validation() {
+                    if !validation(intent) {
                         success = false;
                     }
                 }
@@ -6,6 +6,7 @@ use leptos::{
     prelude::{AnyView, RwSignal, Signal},
     reactive::wrappers::write::SignalSetter,
 };
+use serde::{Deserialize, Serialize};
 use std::{
     fmt::Display,
     marker::{Send, Sync},
@@ -15,17 +16,28 @@ use std::{
 
 pub mod button;
 pub mod checkbox;
+pub mod checkbox_group;
+pub mod combobox;
 pub mod custom;
+pub mod date_input;
+pub mod file_input;
 pub mod group;
 pub mod heading;
 pub mod hidden;
+pub mod multi_select;
+pub mod number_input;
+pub mod otp_input;
 pub mod output;
+pub mod password;
 pub mod radio_buttons;
+pub mod range_slider;
 pub mod select;
 pub mod slider;
 pub mod spacer;
 pub mod stepper;
 pub mod submit;
+pub mod switch;
+pub mod tags_input;
 pub mod text_area;
 pub mod text_input;
 
@@ -35,7 +47,7 @@ pub trait ValidationFn<FDT: ?Sized>:
     Fn(&FDT) -> Result<(), String> + Send + Sync + 'static
 {
 }
-pub trait ValidationCb: Fn() -> bool + 'static {}
+pub trait ValidationCb: Fn(Option<&str>) -> bool + 'static {}
 pub trait ParseFn<CR, FDT>: Fn(CR) -> Result<FDT, String> + Send + Sync + 'static {}
 pub trait UnparseFn<CR, FDT>: Fn(FDT) -> CR + 'static {}
 pub trait FieldGetter<FD, FDT>: Fn(&FD) -> FDT + Send + Sync + 'static {}
@@ -53,7 +65,7 @@ pub trait RenderFn<FS, FD: 'static>:
 impl<B, T> BuilderFn<B> for T where T: Fn(B) -> B {}
 impl<B, CX, T> BuilderCxFn<B, CX> for T where T: Fn(B, Arc<CX>) -> B {}
 impl<FDT, T> ValidationFn<FDT> for T where T: Fn(&FDT) -> Result<(), String> + Send + Sync + 'static {}
-impl<T> ValidationCb for T where T: Fn() -> bool + 'static {}
+impl<T> ValidationCb for T where T: Fn(Option<&str>) -> bool + 'static {}
 impl<CR, FDT, F> ParseFn<CR, FDT> for F where
     F: Fn(CR) -> Result<FDT, String> + Send + Sync + 'static
 {
@@ -119,6 +131,118 @@ impl ValidationState {
     }
 }
 
+/// The submit intents that a control's validation runs for.
+///
+/// See [`SubmitData`](submit::SubmitData) for how a submission gets an
+/// intent.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum IntentFilter {
+    /// Validation runs for any intent.
+    #[default]
+    Any,
+    /// Validation only runs for the listed intents.
+    Only(Vec<String>),
+    /// Validation runs for all but the listed intents.
+    Except(Vec<String>),
+}
+impl IntentFilter {
+    /// Returns true if validation should run for a submission with the
+    /// given intent.
+    ///
+    /// Submissions without an intent run all validations.
+    pub fn runs_for(&self, intent: Option<&str>) -> bool {
+        let Some(intent) = intent else {
+            return true;
+        };
+        match self {
+            IntentFilter::Any => true,
+            IntentFilter::Only(intents) => intents.iter().any(|i| i == intent),
+            IntentFilter::Except(intents) => !intents.iter().any(|i| i == intent),
+        }
+    }
+}
+
+/// A choice from a list of options, or the text the user typed for the
+/// "other" option.
+///
+/// This is the field type for selects and radio buttons with
+/// [`with_other_option`](ControlBuilder::with_other_option).
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Choice<T> {
+    /// One of the listed options was picked.
+    Listed(T),
+    /// The "other" option was picked, with the text the user typed.
+    Other(String),
+}
+impl<T: Default> Default for Choice<T> {
+    fn default() -> Self {
+        Choice::Listed(T::default())
+    }
+}
+
+/// Marks a control value as the text typed for the "other" option.
+const OTHER_MARKER: &str = "\u{1f}other\u{1f}";
+
+/// Gets the control value for the "other" option with the typed text.
+///
+/// Styles should set this as the value when the "other" option is picked
+/// or its text changes.
+pub fn other_value(text: &str) -> String {
+    format!("{}{}", OTHER_MARKER, text)
+}
+
+/// Gets the typed text if the control value is for the "other" option.
+pub fn other_text(value: &str) -> Option<&str> {
+    value.strip_prefix(OTHER_MARKER)
+}
+
+impl<FD, C, T> ControlBuilder<FD, C, Choice<T>>
+where
+    FD: FormToolData,
+    C: ControlData<FD, ReturnType = String>,
+    T: FromStr + ToString + 'static,
+    <T as FromStr>::Err: ToString,
+{
+    /// Sets the parse functions for a [`Choice`], using the [`FromStr`] and
+    /// [`ToString`] traits for the listed options.
+    ///
+    /// Parsing fails if the "other" option is picked without any text, and
+    /// so does validation, so the [`FormValidator`](crate::FormValidator)
+    /// rejects it too.
+    pub(crate) fn parse_choice(mut self) -> Self {
+        self.parse_fn = Some(Box::new(|value: String| match other_text(&value) {
+            Some(text) if text.trim().is_empty() => Err(String::from("Please specify")),
+            Some(text) => Ok(Choice::Other(text.to_string())),
+            None => value
+                .parse::<T>()
+                .map(Choice::Listed)
+                .map_err(|e| e.to_string()),
+        }));
+        self.unparse_fn = Some(Box::new(|field: Choice<T>| match field {
+            Choice::Listed(value) => value.to_string(),
+            Choice::Other(text) => other_value(&text),
+        }));
+        self.field_validations
+            .push(Arc::new(|_fd: &FD, field: &Choice<T>| match field {
+                Choice::Other(text) if text.trim().is_empty() => {
+                    Err(String::from("Please specify"))
+                }
+                _ => Ok(()),
+            }));
+        self
+    }
+}
+
+/// How a control with several options lays the options out.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum OptionLayout {
+    /// Each option is on its own line.
+    #[default]
+    Stacked,
+    /// The options are on one line.
+    Inline,
+}
+
 /// The possibilities for when a control updates the form data.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub enum UpdateEvent {
@@ -274,9 +398,18 @@ pub(crate) struct BuiltControlData<FD: FormToolData, C: ControlData<FD>, FDT> {
     pub(crate) parse_fn: Box<dyn ParseFn<C::ReturnType, FDT>>,
     pub(crate) unparse_fn: Box<dyn UnparseFn<C::ReturnType, FDT>>,
     pub(crate) validation_fn: Option<Arc<dyn ValidationFn<FD>>>,
+    pub(crate) intents: IntentFilter,
     pub(crate) show_when: Option<Arc<dyn ShowWhenFn<FD, FD::Context>>>,
+    /// Whether the validation should run again whenever the form data
+    /// changes, once the user has entered a value.
+    pub(crate) revalidate_on_change: bool,
 }
 
+/// A validation that is given the control's field along with the form
+/// data, so that it can be compared with other fields.
+pub(crate) type FieldValidationFn<FD, FDT> =
+    dyn Fn(&FD, &FDT) -> Result<(), String> + Send + Sync + 'static;
+
 /// A builder for a interactive control.
 pub struct ControlBuilder<FD: FormToolData, C: ControlData<FD>, FDT> {
     pub(crate) getter: Option<Arc<dyn FieldGetter<FD, FDT>>>,
@@ -284,6 +417,13 @@ pub struct ControlBuilder<FD: FormToolData, C: ControlData<FD>, FDT> {
     pub(crate) parse_fn: Option<Box<dyn ParseFn<C::ReturnType, FDT>>>,
     pub(crate) unparse_fn: Option<Box<dyn UnparseFn<C::ReturnType, FDT>>>,
     pub(crate) validation_fn: Option<Arc<dyn ValidationFn<FD>>>,
+    /// The validations that use the field, which are run after the
+    /// `validation_fn` once the getter is known.
+    pub(crate) field_validations: Vec<Arc<FieldValidationFn<FD, FDT>>>,
+    /// Whether the validation depends on other fields, so it should run
+    /// again when they change.
+    pub(crate) revalidate_on_change: bool,
+    pub(crate) intents: IntentFilter,
     pub(crate) style_attributes: Vec<<FD::Style as FormStyle>::StylingAttributes>,
     pub(crate) show_when: Option<Arc<dyn ShowWhenFn<FD, FD::Context>>>,
     pub data: C,
@@ -299,6 +439,9 @@ impl<FD: FormToolData, C: ControlData<FD>, FDT> ControlBuilder<FD, C, FDT> {
             parse_fn: None,
             unparse_fn: None,
             validation_fn: None,
+            field_validations: Vec::new(),
+            revalidate_on_change: false,
+            intents: IntentFilter::Any,
             style_attributes: Vec::new(),
             show_when: None,
         }
@@ -307,7 +450,10 @@ impl<FD: FormToolData, C: ControlData<FD>, FDT> ControlBuilder<FD, C, FDT> {
     /// Builds the builder into the data needed to render the control.
     ///
     /// This fails if a required field was not specified.
-    pub(crate) fn build(self) -> Result<BuiltControlData<FD, C, FDT>, ControlBuildError> {
+    pub(crate) fn build(self) -> Result<BuiltControlData<FD, C, FDT>, ControlBuildError>
+    where
+        FDT: 'static,
+    {
         let getter = match self.getter {
             Some(getter) => getter,
             None => return Err(ControlBuildError::MissingGetter),
@@ -325,6 +471,26 @@ impl<FD: FormToolData, C: ControlData<FD>, FDT> ControlBuilder<FD, C, FDT> {
             None => return Err(ControlBuildError::MissingUnParseFn),
         };
 
+        let validation_fn = match self.field_validations.is_empty() {
+            true => self.validation_fn,
+            false => {
+                let validation_fn = self.validation_fn;
+                let field_validations = self.field_validations;
+                let getter = getter.clone();
+                let combined = move |fd: &FD| {
+                    if let Some(ref validation_fn) = validation_fn {
+                        validation_fn(fd)?;
+                    }
+                    let field = getter(fd);
+                    for field_validation in field_validations.iter() {
+                        field_validation(fd, &field)?;
+                    }
+                    Ok(())
+                };
+                Some(Arc::new(combined) as Arc<dyn ValidationFn<FD>>)
+            }
+        };
+
         Ok(BuiltControlData {
             render_data: ControlRenderData {
                 data: self.data,
@@ -334,8 +500,10 @@ impl<FD: FormToolData, C: ControlData<FD>, FDT> ControlBuilder<FD, C, FDT> {
             setter,
             parse_fn,
             unparse_fn,
-            validation_fn: self.validation_fn,
+            validation_fn,
+            intents: self.intents,
             show_when: self.show_when,
+            revalidate_on_change: self.revalidate_on_change,
         })
     }
 
@@ -615,6 +783,37 @@ where
     }
 }
 
+impl<FD, C, FDT> ControlBuilder<FD, C, FDT>
+where
+    FD: FormToolData,
+    C: ControlData<FD, ReturnType = Vec<String>>,
+{
+    /// Sets the parse functions to use the [`FromStr`] and [`ToString`]
+    /// traits on each item of a collection, like a `Vec<T>` or
+    /// `HashSet<T>`.
+    ///
+    /// The parse and unparse functions define how to turn what the user
+    /// types in the form into what is stored in the form data struct and
+    /// vice versa.
+    pub fn parse_strings<T>(mut self) -> Self
+    where
+        FDT: FromIterator<T> + IntoIterator<Item = T>,
+        T: FromStr + ToString,
+        <T as FromStr>::Err: ToString,
+    {
+        self.parse_fn = Some(Box::new(|control_return_value: Vec<String>| {
+            control_return_value
+                .iter()
+                .map(|value| value.parse::<T>().map_err(|e| e.to_string()))
+                .collect()
+        }));
+        self.unparse_fn = Some(Box::new(|field: FDT| {
+            field.into_iter().map(|value| value.to_string()).collect()
+        }));
+        self
+    }
+}
+
 impl<FD: FormToolData, C: ValidatedControlData<FD>, FDT> ControlBuilder<FD, C, FDT> {
     /// Sets the validation function for this control.
     ///
@@ -633,4 +832,271 @@ impl<FD: FormToolData, C: ValidatedControlData<FD>, FDT> ControlBuilder<FD, C, F
         self.validation_fn = Some(Arc::new(validation_fn));
         self
     }
+
+    /// Only runs this control's validation when the form is submitted with
+    /// the given intent.
+    ///
+    /// This can be called multiple times to run the validation for several
+    /// intents. Submissions without an intent always run the validation.
+    ///
+    /// This can't be mixed with
+    /// [`skip_validation_for`](Self::skip_validation_for), which debug
+    /// builds assert.
+    pub fn validate_for(mut self, intent: impl ToString) -> Self {
+        debug_assert!(
+            !matches!(self.intents, IntentFilter::Except(_)),
+            "validate_for can't be mixed with skip_validation_for"
+        );
+        match self.intents {
+            IntentFilter::Only(ref mut intents) => intents.push(intent.to_string()),
+            _ => self.intents = IntentFilter::Only(vec![intent.to_string()]),
+        }
+        self
+    }
+
+    /// Skips this control's validation when the form is submitted with the
+    /// given intent.
+    ///
+    /// This can be called multiple times to skip the validation for several
+    /// intents.
+    ///
+    /// This can't be mixed with [`validate_for`](Self::validate_for), which
+    /// debug builds assert.
+    pub fn skip_validation_for(mut self, intent: impl ToString) -> Self {
+        debug_assert!(
+            !matches!(self.intents, IntentFilter::Only(_)),
+            "skip_validation_for can't be mixed with validate_for"
+        );
+        match self.intents {
+            IntentFilter::Except(ref mut intents) => intents.push(intent.to_string()),
+            _ => self.intents = IntentFilter::Except(vec![intent.to_string()]),
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{form_builder::FormBuilder, styles::GridFormStyle};
+    use std::collections::HashSet;
+
+    #[derive(Clone, Default)]
+    struct Survey {
+        color: Choice<String>,
+        count: Choice<u32>,
+    }
+    impl FormToolData for Survey {
+        type Style = GridFormStyle;
+        type Context = ();
+
+        fn build_form(fb: FormBuilder<Self>) -> FormBuilder<Self> {
+            fb.select(|c| {
+                c.named("color")
+                    .with_options(["Red", "Blue"].into_iter())
+                    .with_other_option("Other")
+                    .getter(|fd| fd.color.clone())
+                    .setter(|fd, value| fd.color = value)
+            })
+            .radio_buttons(|c| {
+                c.named("count")
+                    .with_options(["1", "2"].into_iter())
+                    .with_other_option("Other")
+                    .getter(|fd| fd.count.clone())
+                    .setter(|fd, value| fd.count = value)
+            })
+        }
+    }
+
+    fn parse(value: &str) -> Result<Choice<u32>, String> {
+        let builder = ControlBuilder::<Survey, select::SelectBuildData<Survey>, Choice<u32>>::new(
+            Default::default(),
+        )
+        .parse_choice();
+        (builder
+            .parse_fn
+            .expect("parse_choice sets the parse function"))(value.to_string())
+    }
+
+    fn unparse(field: Choice<u32>) -> String {
+        let builder = ControlBuilder::<Survey, select::SelectBuildData<Survey>, Choice<u32>>::new(
+            Default::default(),
+        )
+        .parse_choice();
+        (builder
+            .unparse_fn
+            .expect("parse_choice sets the unparse function"))(field)
+    }
+
+    fn intents() -> ControlBuilder<Survey, select::SelectBuildData<Survey>, Choice<u32>> {
+        ControlBuilder::new(Default::default())
+    }
+
+    #[test]
+    fn any_intent_runs_for_everything() {
+        let filter = IntentFilter::Any;
+        assert!(filter.runs_for(None));
+        assert!(filter.runs_for(Some("draft")));
+        assert!(filter.runs_for(Some("")));
+    }
+
+    #[test]
+    fn only_runs_for_the_listed_intents() {
+        let filter = IntentFilter::Only(vec![String::from("publish"), String::from("review")]);
+        assert!(filter.runs_for(Some("publish")));
+        assert!(filter.runs_for(Some("review")));
+        assert!(!filter.runs_for(Some("draft")));
+        assert!(!filter.runs_for(Some("Publish")));
+        // submissions without an intent run everything
+        assert!(filter.runs_for(None));
+    }
+
+    #[test]
+    fn except_skips_the_listed_intents() {
+        let filter = IntentFilter::Except(vec![String::from("draft")]);
+        assert!(!filter.runs_for(Some("draft")));
+        assert!(filter.runs_for(Some("publish")));
+        assert!(filter.runs_for(None));
+    }
+
+    #[test]
+    fn intent_builders_collect_intents() {
+        let builder = intents().validate_for("publish").validate_for("review");
+        assert_eq!(
+            builder.intents,
+            IntentFilter::Only(vec![String::from("publish"), String::from("review")])
+        );
+        let builder = intents()
+            .skip_validation_for("draft")
+            .skip_validation_for("autosave");
+        assert_eq!(
+            builder.intents,
+            IntentFilter::Except(vec![String::from("draft"), String::from("autosave")])
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "can't be mixed")]
+    fn intent_builders_cant_be_mixed() {
+        let _ = intents()
+            .validate_for("publish")
+            .skip_validation_for("draft");
+    }
+
+    fn parse_strings<FDT>(values: &[&str]) -> Result<FDT, String>
+    where
+        FDT: FromIterator<u32> + IntoIterator<Item = u32>,
+    {
+        let builder = ControlBuilder::<Survey, checkbox_group::CheckboxGroupData, FDT>::new(
+            Default::default(),
+        )
+        .parse_strings::<u32>();
+        let values = values.iter().map(|value| value.to_string()).collect();
+        (builder
+            .parse_fn
+            .expect("parse_strings sets the parse function"))(values)
+    }
+
+    #[test]
+    fn strings_parse_into_a_vec() {
+        assert_eq!(parse_strings::<Vec<u32>>(&[]), Ok(vec![]));
+        assert_eq!(
+            parse_strings::<Vec<u32>>(&["3", "1", "3"]),
+            Ok(vec![3, 1, 3])
+        );
+        assert!(parse_strings::<Vec<u32>>(&["3", "x"]).is_err());
+    }
+
+    #[test]
+    fn strings_parse_into_a_set() {
+        let set = parse_strings::<HashSet<u32>>(&["3", "1", "3"]);
+        assert_eq!(set, Ok(HashSet::from([1, 3])));
+        assert!(parse_strings::<HashSet<u32>>(&["-1"]).is_err());
+
+        let builder =
+            ControlBuilder::<Survey, checkbox_group::CheckboxGroupData, HashSet<u32>>::new(
+                Default::default(),
+            )
+            .parse_strings::<u32>();
+        let unparse = builder
+            .unparse_fn
+            .expect("parse_strings sets the unparse function");
+        assert_eq!(unparse(HashSet::from([7])), vec![String::from("7")]);
+    }
+
+    #[test]
+    fn other_value_is_marked() {
+        let value = other_value("Green");
+        assert!(value.starts_with(OTHER_MARKER));
+        assert_eq!(other_text(&value), Some("Green"));
+        assert_eq!(other_text(&other_value("")), Some(""));
+        assert_eq!(other_text("Green"), None);
+        assert_eq!(other_text(""), None);
+    }
+
+    #[test]
+    fn listed_options_are_parsed() {
+        assert_eq!(parse("2"), Ok(Choice::Listed(2)));
+        assert!(parse("two").is_err());
+        // a blank value is not the "other" option
+        assert!(parse("").is_err());
+        assert_eq!(unparse(Choice::Listed(2)), "2");
+    }
+
+    #[test]
+    fn other_text_is_kept_as_typed() {
+        assert_eq!(
+            parse(&other_value("about 3")),
+            Ok(Choice::Other(String::from("about 3")))
+        );
+        assert_eq!(
+            parse(&other_value(" 3 ")),
+            Ok(Choice::Other(String::from(" 3 ")))
+        );
+        // the marker is what makes it the "other" option, even for a number
+        assert_eq!(
+            parse(&other_value("2")),
+            Ok(Choice::Other(String::from("2")))
+        );
+        let field = Choice::Other(String::from("about 3"));
+        assert_eq!(parse(&unparse(field.clone())), Ok(field));
+    }
+
+    #[test]
+    fn empty_other_text_needs_specifying() {
+        assert_eq!(parse(&other_value("")), Err(String::from("Please specify")));
+        assert_eq!(
+            parse(&other_value("  ")),
+            Err(String::from("Please specify"))
+        );
+    }
+
+    #[test]
+    fn validator_rejects_empty_other_text() {
+        let validator = Survey::get_validator(());
+        let valid = Survey {
+            color: Choice::Other(String::from("Green")),
+            count: Choice::Listed(1),
+        };
+        assert_eq!(validator.validate(&valid), Ok(()));
+
+        let blank_select = Survey {
+            color: Choice::Other(String::new()),
+            ..valid.clone()
+        };
+        assert_eq!(
+            validator.validate(&blank_select),
+            Err(String::from("Please specify"))
+        );
+
+        let blank_radio = Survey {
+            count: Choice::Other(String::from(" ")),
+            ..valid
+        };
+        assert_eq!(
+            validator.validate(&blank_radio),
+            Err(String::from("Please specify"))
+        );
+    }
 }
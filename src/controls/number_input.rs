@@ -0,0 +1,595 @@
+use super::{
+    BuilderCxFn, BuilderFn, ControlBuilder, ControlData, ControlRenderData, ValidatedControlData,
+    ValidationState,
+};
+use crate::{form::FormToolData, form_builder::FormBuilder, styles::FormStyle};
+use leptos::{
+    prelude::{AnyView, RwSignal, Signal},
+    reactive::wrappers::write::SignalSetter,
+};
+use std::{
+    num::{IntErrorKind, ParseIntError},
+    str::FromStr,
+};
+
+/// The separators and currency placement that a locale uses for numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NumberLocale {
+    /// The character between the whole and fractional parts.
+    pub decimal: char,
+    /// The character between groups of thousands, if any.
+    pub grouping: Option<char>,
+    /// If the currency symbol goes after the number, like `1.234,56 €`,
+    /// instead of before it, like `$1,234.56`.
+    pub currency_after: bool,
+}
+impl Default for NumberLocale {
+    fn default() -> Self {
+        NumberLocale::EN
+    }
+}
+impl NumberLocale {
+    /// English style numbers, like `1,234.56`.
+    pub const EN: NumberLocale = NumberLocale {
+        decimal: '.',
+        grouping: Some(','),
+        currency_after: false,
+    };
+    /// German style numbers, like `1.234,56`.
+    pub const DE: NumberLocale = NumberLocale {
+        decimal: ',',
+        grouping: Some('.'),
+        currency_after: true,
+    };
+    /// French style numbers, like `1 234,56`, grouped with a non-breaking
+    /// space.
+    pub const FR: NumberLocale = NumberLocale {
+        decimal: ',',
+        grouping: Some('\u{a0}'),
+        currency_after: true,
+    };
+    /// Swiss style numbers, like `1'234.56`.
+    pub const CH: NumberLocale = NumberLocale {
+        decimal: '.',
+        grouping: Some('\''),
+        currency_after: false,
+    };
+
+    /// Gets the locale for a language tag, like `"de-DE"` or `"fr"`.
+    ///
+    /// This covers the common european and english locales; anything else
+    /// uses [`NumberLocale::EN`].
+    pub fn from_tag(tag: &str) -> Self {
+        let tag = tag.replace('_', "-").to_ascii_lowercase();
+        if tag.ends_with("-ch") || tag.ends_with("-li") {
+            return NumberLocale::CH;
+        }
+        let language = tag.split('-').next().unwrap_or_default();
+        match language {
+            "de" | "es" | "it" | "nl" | "pt" | "id" | "tr" | "da" | "el" | "hr" | "ro" | "sl"
+            | "sr" => NumberLocale::DE,
+            "fr" | "ru" | "pl" | "cs" | "sk" | "sv" | "nb" | "no" | "fi" | "uk" | "hu" | "bg"
+            | "et" | "lt" | "lv" => NumberLocale::FR,
+            _ => NumberLocale::EN,
+        }
+    }
+
+    fn is_grouping(&self, c: char) -> bool {
+        match self.grouping {
+            Some(grouping) if grouping.is_whitespace() => c.is_whitespace() || c == '\u{202f}',
+            Some(grouping) => c == grouping,
+            None => false,
+        }
+    }
+}
+impl From<&str> for NumberLocale {
+    fn from(tag: &str) -> Self {
+        NumberLocale::from_tag(tag)
+    }
+}
+
+/// How numbers are shown to and parsed from the user.
+///
+/// Numbers are shown with the locale's separators, with an optional
+/// currency symbol and a fixed number of decimal places.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct NumberFormat {
+    pub locale: NumberLocale,
+    pub currency: Option<String>,
+    pub decimals: Option<u32>,
+}
+impl NumberFormat {
+    /// Creates a new number format for the locale.
+    pub fn new(locale: impl Into<NumberLocale>) -> Self {
+        NumberFormat {
+            locale: locale.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the currency symbol that is shown with the number.
+    ///
+    /// The symbol is optional when parsing.
+    pub fn currency(mut self, symbol: impl ToString) -> Self {
+        self.currency = Some(symbol.to_string());
+        self
+    }
+
+    /// Sets the number of decimal places that are shown.
+    ///
+    /// Parsing fails when more decimal places are entered.
+    pub fn decimals(mut self, decimals: u32) -> Self {
+        self.decimals = Some(decimals);
+        self
+    }
+
+    /// Parses the user's text into a plain number, like `-1234.5`.
+    ///
+    /// Empty text gives an empty string.
+    pub fn parse(&self, text: &str) -> Result<String, String> {
+        let mut text = text.trim();
+        if let Some(ref currency) = self.currency {
+            text = text
+                .strip_prefix(currency.as_str())
+                .or_else(|| text.strip_suffix(currency.as_str()))
+                .unwrap_or(text)
+                .trim();
+        }
+        if text.is_empty() {
+            return Ok(String::new());
+        }
+
+        let (negative, text) = match text.strip_prefix(['-', '\u{2212}']) {
+            Some(rest) => (true, rest.trim_start()),
+            None => (false, text.strip_prefix('+').unwrap_or(text)),
+        };
+        // the currency can also be after the sign, like -$5
+        let text = match self.currency {
+            Some(ref currency) => text.strip_prefix(currency.as_str()).unwrap_or(text),
+            None => text,
+        };
+
+        let mut whole = String::new();
+        let mut fraction = None::<String>;
+        for c in text.chars() {
+            if c == self.locale.decimal && fraction.is_none() {
+                fraction = Some(String::new());
+            } else if c.is_ascii_digit() {
+                fraction.as_mut().unwrap_or(&mut whole).push(c);
+            } else if !(self.locale.is_grouping(c) && fraction.is_none()) {
+                return Err(String::from("Must be a number"));
+            }
+        }
+        if whole.is_empty() && fraction.as_ref().is_none_or(String::is_empty) {
+            return Err(String::from("Must be a number"));
+        }
+
+        let mut plain = String::new();
+        if negative {
+            plain.push('-');
+        }
+        match whole.trim_start_matches('0') {
+            "" => plain.push('0'),
+            whole => plain.push_str(whole),
+        }
+        if let Some(fraction) = fraction.filter(|f| !f.is_empty()) {
+            if let Some(decimals) = self.decimals {
+                if fraction.len() > decimals as usize {
+                    return Err(match decimals {
+                        0 => String::from("Must be a whole number"),
+                        1 => String::from("Can have at most 1 decimal place"),
+                        _ => format!("Can have at most {} decimal places", decimals),
+                    });
+                }
+            }
+            plain.push('.');
+            plain.push_str(&fraction);
+        }
+        Ok(plain)
+    }
+
+    /// Formats a plain number, like `-1234.5`, for the user.
+    ///
+    /// Text that isn't a plain number is returned as is.
+    pub fn format(&self, plain: &str) -> String {
+        let (negative, digits) = match plain.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, plain),
+        };
+        let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        if whole.is_empty()
+            || !whole.chars().all(|c| c.is_ascii_digit())
+            || !fraction.chars().all(|c| c.is_ascii_digit())
+        {
+            return plain.to_string();
+        }
+
+        let mut number = String::new();
+        for (i, c) in whole.chars().enumerate() {
+            if i > 0 && (whole.len() - i) % 3 == 0 {
+                if let Some(grouping) = self.locale.grouping {
+                    number.push(grouping);
+                }
+            }
+            number.push(c);
+        }
+        let mut fraction = fraction.to_string();
+        if let Some(decimals) = self.decimals {
+            while fraction.len() < decimals as usize {
+                fraction.push('0');
+            }
+        }
+        if !fraction.is_empty() {
+            number.push(self.locale.decimal);
+            number.push_str(&fraction);
+        }
+
+        let sign = if negative { "-" } else { "" };
+        match self.currency {
+            Some(ref currency) if self.locale.currency_after => {
+                format!("{sign}{number}\u{a0}{currency}")
+            }
+            Some(ref currency) => format!("{sign}{currency}{number}"),
+            None => format!("{sign}{number}"),
+        }
+    }
+}
+impl From<NumberLocale> for NumberFormat {
+    fn from(locale: NumberLocale) -> Self {
+        NumberFormat::new(locale)
+    }
+}
+impl From<&str> for NumberFormat {
+    fn from(tag: &str) -> Self {
+        NumberFormat::new(tag)
+    }
+}
+
+/// A number that can be entered with a locale formatted input.
+///
+/// This converts between the value and a plain number, like `-1234.5`.
+///
+/// This is implemented for the integer and float types, and for [`Option`]s
+/// of other [`FormNumber`]s, where an empty input is `None`. With the
+/// `decimal` feature, it is implemented for `rust_decimal::Decimal`.
+pub trait FormNumber: Sized {
+    /// Parses a plain number.
+    fn from_plain(plain: &str) -> Result<Self, String>;
+
+    /// Converts this value to a plain number, rounded to the number of
+    /// decimal places if given.
+    fn to_plain(&self, decimals: Option<u32>) -> String;
+}
+
+macro_rules! impl_form_number_int {
+    ($($t:ty),*) => {$(
+        impl FormNumber for $t {
+            fn from_plain(plain: &str) -> Result<Self, String> {
+                // a fraction of zeros is still a whole number, like the
+                // "1234.00" of a format with 2 decimal places
+                let (whole, fraction) = plain.split_once('.').unwrap_or((plain, ""));
+                if !fraction.chars().all(|c| c == '0') {
+                    return Err(String::from("Must be a whole number"));
+                }
+                parse_int(whole)
+            }
+
+            fn to_plain(&self, _decimals: Option<u32>) -> String {
+                self.to_string()
+            }
+        }
+    )*};
+}
+impl_form_number_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Parses the whole part of a plain number into an integer type.
+fn parse_int<T: FromStr<Err = ParseIntError>>(whole: &str) -> Result<T, String> {
+    // "-0" is zero, which fits in unsigned types too
+    let whole = match whole.strip_prefix('-') {
+        Some(digits) if !digits.is_empty() && digits.chars().all(|c| c == '0') => digits,
+        _ => whole,
+    };
+    whole.parse().map_err(|e: ParseIntError| match e.kind() {
+        IntErrorKind::PosOverflow => String::from("Number is too large"),
+        IntErrorKind::NegOverflow => String::from("Number is too small"),
+        IntErrorKind::InvalidDigit if whole.starts_with('-') => {
+            String::from("Must not be negative")
+        }
+        _ => String::from("Must be a number"),
+    })
+}
+
+macro_rules! impl_form_number_float {
+    ($($t:ty),*) => {$(
+        impl FormNumber for $t {
+            fn from_plain(plain: &str) -> Result<Self, String> {
+                plain.parse().map_err(|_| String::from("Must be a number"))
+            }
+
+            fn to_plain(&self, decimals: Option<u32>) -> String {
+                match decimals {
+                    Some(decimals) => format!("{:.*}", decimals as usize, self),
+                    None => self.to_string(),
+                }
+            }
+        }
+    )*};
+}
+impl_form_number_float!(f32, f64);
+
+impl<T: FormNumber> FormNumber for Option<T> {
+    fn from_plain(plain: &str) -> Result<Self, String> {
+        if plain.is_empty() {
+            return Ok(None);
+        }
+        T::from_plain(plain).map(Some)
+    }
+
+    fn to_plain(&self, decimals: Option<u32>) -> String {
+        match self {
+            Some(value) => value.to_plain(decimals),
+            None => String::new(),
+        }
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl FormNumber for rust_decimal::Decimal {
+    fn from_plain(plain: &str) -> Result<Self, String> {
+        plain.parse().map_err(|_| String::from("Must be a number"))
+    }
+
+    fn to_plain(&self, decimals: Option<u32>) -> String {
+        match decimals {
+            Some(decimals) => format!("{:.*}", decimals as usize, self.round_dp(decimals)),
+            None => self.normalize().to_string(),
+        }
+    }
+}
+
+/// Data used for the number input control.
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NumberInputData {
+    pub name: String,
+    pub label: Option<String>,
+    pub placeholder: Option<String>,
+    /// If the number can have a fractional part.
+    ///
+    /// This picks the keyboard that is shown on mobile devices.
+    pub decimal: bool,
+}
+
+impl<FD: FormToolData> ControlData<FD> for NumberInputData {
+    /// The text, as the user entered it.
+    type ReturnType = String;
+
+    fn render_control<FS: FormStyle>(
+        fs: &FS,
+        _fd: RwSignal<FD>,
+        control: ControlRenderData<FS, Self>,
+        value_getter: Signal<Self::ReturnType>,
+        value_setter: SignalSetter<Self::ReturnType>,
+        validation_state: Signal<ValidationState>,
+    ) -> AnyView {
+        fs.number_input(control, value_getter, value_setter, validation_state)
+    }
+}
+impl<FD: FormToolData> ValidatedControlData<FD> for NumberInputData {}
+
+impl<FD: FormToolData> FormBuilder<FD> {
+    /// Builds a number input control and adds it to the form.
+    ///
+    /// Unlike the [`stepper`](FormBuilder::stepper), this is a text input
+    /// that takes numbers formatted for the user's locale; see
+    /// [`parse_locale_number`](ControlBuilder::parse_locale_number).
+    pub fn number_input<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderFn<ControlBuilder<FD, NumberInputData, FDT>>,
+    ) -> Self {
+        self.new_control(builder)
+    }
+
+    /// Builds a number input control using the form's context and adds it
+    /// to the form.
+    pub fn number_input_cx<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderCxFn<ControlBuilder<FD, NumberInputData, FDT>, FD::Context>,
+    ) -> Self {
+        self.new_control_cx(builder)
+    }
+}
+
+impl<FD: FormToolData, FDT> ControlBuilder<FD, NumberInputData, FDT> {
+    /// Sets the name of the number input.
+    ///
+    /// This is used for the html element's "name" attribute.
+    /// In forms, the name attribute is the key that the data is sent
+    /// with.
+    pub fn named(mut self, control_name: impl ToString) -> Self {
+        self.data.name = control_name.to_string();
+        self
+    }
+
+    /// Sets the label for the number input.
+    pub fn labeled(mut self, label: impl ToString) -> Self {
+        self.data.label = Some(label.to_string());
+        self
+    }
+
+    /// Sets the placeholder for the number input.
+    pub fn placeholder(mut self, placeholder: impl ToString) -> Self {
+        self.data.placeholder = Some(placeholder.to_string());
+        self
+    }
+
+    /// Allows numbers with a fractional part to be entered.
+    pub fn decimal(mut self) -> Self {
+        self.data.decimal = true;
+        self
+    }
+}
+
+impl<FD: FormToolData, C: ControlData<FD, ReturnType = String>, FDT: FormNumber + 'static>
+    ControlBuilder<FD, C, FDT>
+{
+    /// Sets the parse functions to parse and format numbers with the given
+    /// [`NumberFormat`], like `"1.234,56"` for `NumberFormat::new("de")`.
+    ///
+    /// A locale or a language tag can be passed in directly for a format
+    /// without a currency or fixed decimal places.
+    ///
+    /// The parse and unparse functions define how to turn what the user
+    /// types in the form into what is stored in the form data struct and
+    /// vice versa.
+    pub fn parse_locale_number(mut self, format: impl Into<NumberFormat>) -> Self {
+        let format = format.into();
+        let unparse_format = format.clone();
+        self.parse_fn = Some(Box::new(move |value: String| {
+            FDT::from_plain(&format.parse(&value)?)
+        }));
+        self.unparse_fn = Some(Box::new(move |field: FDT| {
+            unparse_format.format(&field.to_plain(unparse_format.decimals))
+        }));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checks that `plain` is formatted as `text`, and parses back.
+    fn round_trip(format: &NumberFormat, plain: &str, text: &str) {
+        assert_eq!(format.format(plain), text);
+        assert_eq!(format.parse(text).as_deref(), Ok(plain));
+    }
+
+    #[test]
+    fn english_numbers_round_trip() {
+        let format = NumberFormat::new("en-US");
+        round_trip(&format, "1234567.89", "1,234,567.89");
+        round_trip(&format, "-1234.5", "-1,234.5");
+        round_trip(&format, "12", "12");
+        round_trip(&format, "0.5", "0.5");
+    }
+
+    #[test]
+    fn german_numbers_round_trip() {
+        let format = NumberFormat::new("de-DE");
+        round_trip(&format, "1234567.89", "1.234.567,89");
+        round_trip(&format, "-1234.5", "-1.234,5");
+        assert_eq!(format.parse("1,5").as_deref(), Ok("1.5"));
+    }
+
+    #[test]
+    fn french_numbers_round_trip() {
+        let format = NumberFormat::new("fr");
+        round_trip(&format, "1234567.89", "1\u{a0}234\u{a0}567,89");
+        round_trip(&format, "-1234.5", "-1\u{a0}234,5");
+        // any space is accepted as the grouping
+        assert_eq!(format.parse("1 234,5").as_deref(), Ok("1234.5"));
+        assert_eq!(format.parse("1\u{202f}234,5").as_deref(), Ok("1234.5"));
+    }
+
+    #[test]
+    fn swiss_numbers_round_trip() {
+        let format = NumberFormat::new("de-CH");
+        round_trip(&format, "1234567.89", "1'234'567.89");
+        round_trip(&format, "-1234.5", "-1'234.5");
+    }
+
+    #[test]
+    fn currencies_round_trip() {
+        let dollars = NumberFormat::new("en").currency("$").decimals(2);
+        round_trip(&dollars, "1234.50", "$1,234.50");
+        round_trip(&dollars, "-5.00", "-$5.00");
+        assert_eq!(dollars.parse("1,234.5").as_deref(), Ok("1234.5"));
+
+        let euros = NumberFormat::new("de").currency("€").decimals(2);
+        round_trip(&euros, "1234.56", "1.234,56\u{a0}€");
+        round_trip(&euros, "-1234.56", "-1.234,56\u{a0}€");
+        assert_eq!(euros.parse("1.234,56€").as_deref(), Ok("1234.56"));
+    }
+
+    #[test]
+    fn decimals_are_padded_and_limited() {
+        let format = NumberFormat::new("en").decimals(2);
+        assert_eq!(format.format("1234"), "1,234.00");
+        assert_eq!(format.format("1.5"), "1.50");
+        assert_eq!(
+            format.parse("1.234"),
+            Err(String::from("Can have at most 2 decimal places"))
+        );
+    }
+
+    #[test]
+    fn invalid_numbers_fail() {
+        let format = NumberFormat::new("en");
+        assert_eq!(format.parse("").as_deref(), Ok(""));
+        assert!(format.parse("abc").is_err());
+        assert!(format.parse("-").is_err());
+        assert!(format.parse("1.2.3").is_err());
+        // grouping is not allowed in the fraction
+        assert!(format.parse("1.234,5").is_err());
+    }
+
+    #[test]
+    fn integers_with_decimals_round_trip() {
+        let format = NumberFormat::new("en").decimals(2);
+        let text = format.format(&1234u32.to_plain(format.decimals));
+        assert_eq!(text, "1,234.00");
+        let plain = format.parse(&text).unwrap();
+        assert_eq!(u32::from_plain(&plain), Ok(1234));
+        assert_eq!(
+            u32::from_plain("1234.50"),
+            Err(String::from("Must be a whole number"))
+        );
+    }
+
+    #[test]
+    fn integer_errors_are_specific() {
+        assert_eq!(
+            u32::from_plain("-5"),
+            Err(String::from("Must not be negative"))
+        );
+        assert_eq!(u32::from_plain("-0"), Ok(0));
+        assert_eq!(
+            u8::from_plain("256"),
+            Err(String::from("Number is too large"))
+        );
+        assert_eq!(
+            i8::from_plain("-129"),
+            Err(String::from("Number is too small"))
+        );
+        assert_eq!(i8::from_plain("-128"), Ok(-128));
+    }
+
+    #[test]
+    fn options_are_empty_for_empty_text() {
+        assert_eq!(Option::<i32>::from_plain(""), Ok(None));
+        assert_eq!(Option::<i32>::from_plain("5"), Ok(Some(5)));
+        assert_eq!(Option::<i32>::None.to_plain(None), "");
+    }
+
+    #[test]
+    fn floats_round_to_the_decimals() {
+        assert_eq!(1.005f64.to_plain(Some(1)), "1.0");
+        assert_eq!(2.5f64.to_plain(None), "2.5");
+        assert_eq!(f64::from_plain("-1234.5"), Ok(-1234.5));
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn decimals_round_trip() {
+        use rust_decimal::Decimal;
+
+        let format = NumberFormat::new("de").decimals(2);
+        let value: Decimal = "1234.5".parse().unwrap();
+        let text = format.format(&value.to_plain(format.decimals));
+        assert_eq!(text, "1.234,50");
+        assert_eq!(
+            Decimal::from_plain(&format.parse(&text).unwrap()),
+            Ok(value)
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use super::{
+    BuilderCxFn, BuilderFn, ControlBuilder, ControlData, ControlRenderData, ValidatedControlData,
+    ValidationState,
+};
+use crate::{form::FormToolData, form_builder::FormBuilder, styles::FormStyle};
+use leptos::{
+    prelude::{AnyView, RwSignal, Signal},
+    reactive::wrappers::write::SignalSetter,
+};
+
+/// The characters that can be entered in a one-time code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum OtpCharset {
+    /// Only the digits 0-9.
+    #[default]
+    Digits,
+    /// Ascii letters and digits.
+    Alphanumeric,
+    /// Only ascii letters.
+    Letters,
+}
+impl OtpCharset {
+    /// Returns true if the character can be entered.
+    pub fn accepts(&self, c: char) -> bool {
+        match self {
+            OtpCharset::Digits => c.is_ascii_digit(),
+            OtpCharset::Alphanumeric => c.is_ascii_alphanumeric(),
+            OtpCharset::Letters => c.is_ascii_alphabetic(),
+        }
+    }
+
+    /// Gets the html inputmode, which picks the keyboard shown on mobile
+    /// devices.
+    pub fn input_mode(&self) -> &'static str {
+        match self {
+            OtpCharset::Digits => "numeric",
+            _ => "text",
+        }
+    }
+}
+
+/// Data used for the one-time code input control.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OtpInputData {
+    pub name: String,
+    pub label: Option<String>,
+    /// The number of characters in the code.
+    pub length: usize,
+    pub charset: OtpCharset,
+    /// Whether to submit the form once the whole code is entered.
+    pub auto_submit: bool,
+}
+impl Default for OtpInputData {
+    fn default() -> Self {
+        OtpInputData {
+            name: String::new(),
+            label: None,
+            length: 6,
+            charset: OtpCharset::default(),
+            auto_submit: false,
+        }
+    }
+}
+
+impl<FD: FormToolData> ControlData<FD> for OtpInputData {
+    /// The entered characters, in order.
+    type ReturnType = String;
+
+    fn render_control<FS: FormStyle>(
+        fs: &FS,
+        _fd: RwSignal<FD>,
+        control: ControlRenderData<FS, Self>,
+        value_getter: Signal<Self::ReturnType>,
+        value_setter: SignalSetter<Self::ReturnType>,
+        validation_state: Signal<ValidationState>,
+    ) -> AnyView {
+        fs.otp_input(control, value_getter, value_setter, validation_state)
+    }
+}
+impl<FD: FormToolData> ValidatedControlData<FD> for OtpInputData {}
+
+impl<FD: FormToolData> FormBuilder<FD> {
+    /// Builds a one-time code input control and adds it to the form.
+    ///
+    /// This is entered one character per box, like for a 2FA code or a
+    /// PIN.
+    pub fn otp_input<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderFn<ControlBuilder<FD, OtpInputData, FDT>>,
+    ) -> Self {
+        self.new_control(builder)
+    }
+
+    /// Builds a one-time code input control using the form's context and
+    /// adds it to the form.
+    pub fn otp_input_cx<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderCxFn<ControlBuilder<FD, OtpInputData, FDT>, FD::Context>,
+    ) -> Self {
+        self.new_control_cx(builder)
+    }
+}
+
+impl<FD: FormToolData, FDT> ControlBuilder<FD, OtpInputData, FDT> {
+    /// Sets the name of the one-time code input.
+    ///
+    /// This is used for the html element's "name" attribute.
+    /// In forms, the name attribute is the key that the data is sent
+    /// with.
+    pub fn named(mut self, control_name: impl ToString) -> Self {
+        self.data.name = control_name.to_string();
+        self
+    }
+
+    /// Sets the label for the one-time code input.
+    pub fn labeled(mut self, label: impl ToString) -> Self {
+        self.data.label = Some(label.to_string());
+        self
+    }
+
+    /// Sets the number of characters in the code.
+    ///
+    /// This defaults to 6.
+    pub fn length(mut self, length: usize) -> Self {
+        self.data.length = length;
+        self
+    }
+
+    /// Sets the characters that can be entered.
+    ///
+    /// This defaults to [`OtpCharset::Digits`].
+    pub fn charset(mut self, charset: OtpCharset) -> Self {
+        self.data.charset = charset;
+        self
+    }
+
+    /// Submits the form once the whole code is entered.
+    ///
+    /// The form is submitted like the user clicked submit, so the form is
+    /// validated first.
+    pub fn auto_submit(mut self) -> Self {
+        self.data.auto_submit = true;
+        self
+    }
+}
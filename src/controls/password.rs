@@ -0,0 +1,407 @@
+use super::{
+    BuilderCxFn, BuilderFn, ControlBuilder, ControlData, ControlRenderData, FieldGetter,
+    ValidatedControlData, ValidationState,
+};
+use crate::{form::FormToolData, form_builder::FormBuilder, styles::FormStyle};
+use leptos::{
+    prelude::{AnyView, Get, RwSignal, Signal},
+    reactive::wrappers::write::SignalSetter,
+};
+use std::sync::Arc;
+
+/// Estimates how strong a password is.
+type StrengthFn = dyn Fn(&str) -> PasswordStrength + Send + Sync + 'static;
+
+/// How strong a password is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum PasswordStrength {
+    #[default]
+    VeryWeak,
+    Weak,
+    Fair,
+    Strong,
+    VeryStrong,
+}
+impl PasswordStrength {
+    /// The highest score, for [`PasswordStrength::VeryStrong`].
+    pub const MAX_SCORE: u8 = 4;
+
+    /// Gets the strength as a score from 0 to [`MAX_SCORE`](Self::MAX_SCORE).
+    pub fn score(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Gets the strength for a score, clamping it to
+    /// [`MAX_SCORE`](Self::MAX_SCORE).
+    pub fn from_score(score: u8) -> Self {
+        match score {
+            0 => PasswordStrength::VeryWeak,
+            1 => PasswordStrength::Weak,
+            2 => PasswordStrength::Fair,
+            3 => PasswordStrength::Strong,
+            _ => PasswordStrength::VeryStrong,
+        }
+    }
+
+    /// Gets a human readable name of this strength.
+    pub fn describe(&self) -> &'static str {
+        match self {
+            PasswordStrength::VeryWeak => "Very weak",
+            PasswordStrength::Weak => "Weak",
+            PasswordStrength::Fair => "Fair",
+            PasswordStrength::Strong => "Strong",
+            PasswordStrength::VeryStrong => "Very strong",
+        }
+    }
+}
+
+/// A simple password strength estimate, based on the length and the kinds
+/// of characters used.
+///
+/// For a better estimate, pass something like zxcvbn to
+/// [`strength_fn`](ControlBuilder::strength_fn).
+pub fn estimate_strength(password: &str) -> PasswordStrength {
+    let length_score = match password.chars().count() {
+        0..=7 => 0,
+        8..=11 => 1,
+        12..=15 => 2,
+        _ => 3,
+    };
+    let classes = [
+        password.chars().any(|c| c.is_lowercase()),
+        password.chars().any(|c| c.is_uppercase()),
+        password.chars().any(|c| c.is_numeric()),
+        password.chars().any(|c| !c.is_alphanumeric()),
+    ]
+    .into_iter()
+    .filter(|used| *used)
+    .count();
+    let class_score = match classes {
+        0..=2 => 0,
+        3 => 1,
+        _ => 2,
+    };
+
+    if length_score == 0 {
+        // short passwords are weak no matter what is in them
+        return PasswordStrength::from_score(class_score.min(1));
+    }
+    PasswordStrength::from_score(length_score + class_score)
+}
+
+/// Data used for building the password control.
+#[derive(Clone, Default)]
+pub struct PasswordBuildData {
+    pub name: String,
+    pub label: Option<String>,
+    pub placeholder: Option<String>,
+    /// Whether this is a new password, rather than the current one.
+    ///
+    /// This tells password managers to suggest a new password.
+    pub new_password: bool,
+    /// The function that estimates the password's strength.
+    ///
+    /// This is just a temp value for building, and should not be used
+    /// directly
+    strength: Option<Arc<StrengthFn>>,
+}
+
+/// Data used for the password control.
+#[derive(Debug, Clone)]
+pub struct PasswordData {
+    pub name: String,
+    pub label: Option<String>,
+    pub placeholder: Option<String>,
+    /// Whether this is a new password, rather than the current one.
+    pub new_password: bool,
+    /// The strength of the entered password, if it should be shown.
+    pub strength: Option<Signal<PasswordStrength>>,
+}
+impl PasswordData {
+    /// Gets the html autocomplete attribute for this password.
+    pub fn autocomplete(&self) -> &'static str {
+        if self.new_password {
+            "new-password"
+        } else {
+            "current-password"
+        }
+    }
+}
+
+impl<FD: FormToolData> ControlData<FD> for PasswordBuildData {
+    type ReturnType = String;
+
+    fn render_control<FS: FormStyle>(
+        fs: &FS,
+        _fd: RwSignal<FD>,
+        control: ControlRenderData<FS, Self>,
+        value_getter: Signal<Self::ReturnType>,
+        value_setter: SignalSetter<Self::ReturnType>,
+        validation_state: Signal<ValidationState>,
+    ) -> AnyView {
+        let strength = control
+            .data
+            .strength
+            .clone()
+            .map(|strength| Signal::derive(move || strength(&value_getter.get())));
+
+        let new_control = ControlRenderData {
+            styles: control.styles.clone(),
+            data: PasswordData {
+                name: control.data.name.clone(),
+                label: control.data.label.clone(),
+                placeholder: control.data.placeholder.clone(),
+                new_password: control.data.new_password,
+                strength,
+            },
+        };
+
+        fs.password(new_control, value_getter, value_setter, validation_state)
+    }
+}
+impl<FD: FormToolData> ValidatedControlData<FD> for PasswordBuildData {}
+
+impl<FD: FormToolData> FormBuilder<FD> {
+    /// Builds a password control and adds it to the form.
+    ///
+    /// Unlike a text input with [`password`](ControlBuilder::password), this
+    /// can be revealed by the user and can show the password's strength.
+    pub fn password<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderFn<ControlBuilder<FD, PasswordBuildData, FDT>>,
+    ) -> Self {
+        self.new_control(builder)
+    }
+
+    /// Builds a password control using the form's context and adds it to
+    /// the form.
+    pub fn password_cx<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderCxFn<ControlBuilder<FD, PasswordBuildData, FDT>, FD::Context>,
+    ) -> Self {
+        self.new_control_cx(builder)
+    }
+}
+
+impl<FD: FormToolData, FDT> ControlBuilder<FD, PasswordBuildData, FDT> {
+    /// Sets the name of the password control.
+    ///
+    /// This is used for the html element's "name" attribute.
+    /// In forms, the name attribute is the key that the data is sent
+    /// with.
+    pub fn named(mut self, control_name: impl ToString) -> Self {
+        self.data.name = control_name.to_string();
+        self
+    }
+
+    /// Sets the label for the password control.
+    pub fn labeled(mut self, label: impl ToString) -> Self {
+        self.data.label = Some(label.to_string());
+        self
+    }
+
+    /// Sets the placeholder for the password control.
+    pub fn placeholder(mut self, placeholder: impl ToString) -> Self {
+        self.data.placeholder = Some(placeholder.to_string());
+        self
+    }
+
+    /// Marks this as a new password, like on a sign up form, rather than
+    /// the user's current password.
+    pub fn new_password(mut self) -> Self {
+        self.data.new_password = true;
+        self
+    }
+
+    /// Shows the password's strength, using [`estimate_strength`].
+    pub fn strength_meter(self) -> Self {
+        self.strength_fn(estimate_strength)
+    }
+
+    /// Shows the password's strength, using the given function to estimate
+    /// it.
+    pub fn strength_fn(
+        mut self,
+        strength_fn: impl Fn(&str) -> PasswordStrength + Send + Sync + 'static,
+    ) -> Self {
+        self.data.strength = Some(Arc::new(strength_fn));
+        self
+    }
+}
+
+impl<FD: FormToolData, FDT: PartialEq + 'static> ControlBuilder<FD, PasswordBuildData, FDT> {
+    /// Makes this control confirm another password field, failing
+    /// validation if they are not equal.
+    ///
+    /// This is checked after any
+    /// [`validation_fn`](ControlBuilder::validation_fn), and again whenever
+    /// the form data changes once the user has entered this field. So,
+    /// editing the other field updates the error.
+    pub fn confirms(self, other: impl FieldGetter<FD, FDT>) -> Self {
+        self.confirms_msg(other, "Passwords do not match")
+    }
+
+    /// Makes this control confirm another password field, like
+    /// [`confirms`](Self::confirms), with a custom error message.
+    pub fn confirms_msg(
+        mut self,
+        other: impl FieldGetter<FD, FDT>,
+        msg: impl ToString + Send + Sync + 'static,
+    ) -> Self {
+        self.field_validations
+            .push(Arc::new(move |fd: &FD, field: &FDT| {
+                if *field != other(fd) {
+                    return Err(msg.to_string());
+                }
+                Ok(())
+            }));
+        self.revalidate_on_change = true;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::styles::GridFormStyle;
+
+    #[test]
+    fn short_passwords_are_weak_at_best() {
+        assert_eq!(estimate_strength(""), PasswordStrength::VeryWeak);
+        assert_eq!(estimate_strength("abcdefg"), PasswordStrength::VeryWeak);
+        // 2 classes
+        assert_eq!(estimate_strength("abcDEFG"), PasswordStrength::VeryWeak);
+        // 3 and 4 classes are capped
+        assert_eq!(estimate_strength("abcDE1"), PasswordStrength::Weak);
+        assert_eq!(estimate_strength("aB1!"), PasswordStrength::Weak);
+    }
+
+    #[test]
+    fn length_bands_add_strength() {
+        // one class, so only the length counts
+        assert_eq!(estimate_strength("abcdefgh"), PasswordStrength::Weak);
+        assert_eq!(estimate_strength("abcdefghijk"), PasswordStrength::Weak);
+        assert_eq!(estimate_strength("abcdefghijkl"), PasswordStrength::Fair);
+        assert_eq!(estimate_strength("abcdefghijklmno"), PasswordStrength::Fair);
+        assert_eq!(
+            estimate_strength("abcdefghijklmnop"),
+            PasswordStrength::Strong
+        );
+    }
+
+    #[test]
+    fn class_bands_add_strength() {
+        // 8 characters
+        assert_eq!(estimate_strength("abcdEFGH"), PasswordStrength::Weak);
+        assert_eq!(estimate_strength("abcdEF12"), PasswordStrength::Fair);
+        assert_eq!(estimate_strength("abcdE12!"), PasswordStrength::Strong);
+        // 16 characters
+        assert_eq!(
+            estimate_strength("abcdEF12abcdEF12"),
+            PasswordStrength::VeryStrong
+        );
+        assert_eq!(
+            estimate_strength("abcdE12!abcdE12!"),
+            PasswordStrength::VeryStrong
+        );
+    }
+
+    #[test]
+    fn length_counts_characters() {
+        // 8 characters, but more bytes
+        assert_eq!(estimate_strength("ääääääää"), PasswordStrength::Weak);
+        assert_eq!(estimate_strength("äääääää"), PasswordStrength::VeryWeak);
+    }
+
+    #[test]
+    fn scores_round_trip() {
+        for score in 0..=PasswordStrength::MAX_SCORE {
+            assert_eq!(PasswordStrength::from_score(score).score(), score);
+        }
+        assert_eq!(
+            PasswordStrength::from_score(9),
+            PasswordStrength::VeryStrong
+        );
+    }
+
+    #[derive(Clone, Default)]
+    struct SignUp {
+        password: String,
+        confirm: String,
+    }
+    impl FormToolData for SignUp {
+        type Style = GridFormStyle;
+        type Context = ();
+
+        fn build_form(fb: FormBuilder<Self>) -> FormBuilder<Self> {
+            fb.password(|c| {
+                c.named("password")
+                    .parse_string()
+                    .getter(|fd| fd.password.clone())
+                    .setter(|fd, value| fd.password = value)
+            })
+            // confirming before setting the getter works too
+            .password(|c| {
+                c.named("confirm")
+                    .confirms(|fd: &SignUp| fd.password.clone())
+                    .parse_string()
+                    .getter(|fd| fd.confirm.clone())
+                    .setter(|fd, value| fd.confirm = value)
+            })
+        }
+    }
+
+    fn sign_up(password: &str, confirm: &str) -> SignUp {
+        SignUp {
+            password: password.to_string(),
+            confirm: confirm.to_string(),
+        }
+    }
+
+    #[test]
+    fn confirms_passes_for_equal_passwords() {
+        let validator = SignUp::get_validator(());
+        assert_eq!(validator.validate(&sign_up("hunter22", "hunter22")), Ok(()));
+        assert_eq!(validator.validate(&sign_up("", "")), Ok(()));
+    }
+
+    #[test]
+    fn confirms_fails_for_unequal_passwords() {
+        let validator = SignUp::get_validator(());
+        assert_eq!(
+            validator.validate(&sign_up("hunter22", "hunter2")),
+            Err(String::from("Passwords do not match"))
+        );
+        assert_eq!(
+            validator.validate(&sign_up("hunter22", "")),
+            Err(String::from("Passwords do not match"))
+        );
+    }
+
+    #[test]
+    fn confirms_runs_after_the_validation_fn() {
+        let builder = ControlBuilder::<SignUp, PasswordBuildData, String>::new(Default::default())
+            .confirms_msg(|fd: &SignUp| fd.password.clone(), "Must match")
+            .validation_fn(|fd: &SignUp| match fd.confirm.is_empty() {
+                true => Err(String::from("Required")),
+                false => Ok(()),
+            })
+            .parse_string()
+            .getter(|fd: &SignUp| fd.confirm.clone())
+            .setter(|fd: &mut SignUp, value| fd.confirm = value);
+        let validation_fn = builder
+            .build()
+            .ok()
+            .and_then(|built| built.validation_fn)
+            .expect("the control has a validation");
+        assert_eq!(
+            validation_fn(&sign_up("hunter22", "")),
+            Err(String::from("Required"))
+        );
+        assert_eq!(
+            validation_fn(&sign_up("hunter22", "hunter")),
+            Err(String::from("Must match"))
+        );
+        assert_eq!(validation_fn(&sign_up("hunter22", "hunter22")), Ok(()));
+    }
+}
@@ -1,60 +1,216 @@
 use super::{
-    BuilderCxFn, BuilderFn, ControlBuilder, ControlData, ControlRenderData, ValidatedControlData,
-    ValidationState,
+    BuilderCxFn, BuilderFn, Choice, ControlBuilder, ControlData, ControlRenderData, OptionLayout,
+    ValidatedControlData, ValidationState,
+};
+use crate::{
+    form::FormToolData, form_builder::FormBuilder, form_enum::EnumField, styles::FormStyle,
+    FormEnum,
 };
-use crate::{form::FormToolData, form_builder::FormBuilder, styles::FormStyle};
 use leptos::{
-    prelude::{AnyView, RwSignal, Signal},
+    prelude::{AnyView, Get, RwSignal, Signal},
     reactive::wrappers::write::SignalSetter,
 };
+use std::{str::FromStr, sync::Arc};
 
-/// Data used for the radio buttons control.
+/// An option for a group of radio buttons.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct RadioOption {
+    /// The string to display.
+    pub display: String,
+    /// The value that is picked.
+    pub value: String,
+    /// Extra text shown with the option, if any.
+    pub description: Option<String>,
+    /// Whether the option can't be picked.
+    pub disabled: bool,
+}
+impl RadioOption {
+    /// Creates an option that displays its value.
+    pub fn new(value: impl ToString) -> Self {
+        let value = value.to_string();
+        RadioOption {
+            display: value.clone(),
+            value,
+            ..Default::default()
+        }
+    }
+
+    /// Creates an option with a different value than what is displayed.
+    pub fn valued(display: impl ToString, value: impl ToString) -> Self {
+        RadioOption {
+            display: display.to_string(),
+            value: value.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the extra text shown with the option.
+    pub fn description(mut self, description: impl ToString) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    /// Makes the option unpickable.
+    pub fn disabled(mut self) -> Self {
+        self.disabled = true;
+        self
+    }
+}
+impl From<(String, String)> for RadioOption {
+    fn from((display, value): (String, String)) -> Self {
+        RadioOption::valued(display, value)
+    }
+}
+impl From<String> for RadioOption {
+    fn from(value: String) -> Self {
+        RadioOption::new(value)
+    }
+}
+impl From<RadioOption> for (String, String) {
+    /// Gets the `(display, value)` pair form of the option.
+    fn from(option: RadioOption) -> Self {
+        (option.display, option.value)
+    }
+}
+
+type DynamicOptionsGetter<FD> =
+    Arc<dyn Fn(RwSignal<FD>) -> Vec<RadioOption> + Send + Sync + 'static>;
+/// Data used for building the radio buttons control.
+pub struct RadioButtonsBuildData<FD: FormToolData> {
+    pub name: String,
+    pub label: Option<String>,
+    /// A derived signal for dynamic options for the radio buttons
+    ///
+    /// This is just a temp value for building, and should not be used
+    /// directly
+    dynamic_options: Option<DynamicOptionsGetter<FD>>,
+    /// The options added one at a time, which are shown after the options
+    /// from the signal.
+    ///
+    /// This is just a temp value for building, and should not be used
+    /// directly
+    static_options: Vec<RadioOption>,
+    /// The options for the radio buttons from a signal.
+    pub options: Signal<Vec<RadioOption>>,
+    pub layout: OptionLayout,
+    /// The label of the "other" option, if there is one.
+    ///
+    /// When it is picked, the user can type their own value.
+    pub other_option: Option<String>,
+}
+impl<FD: FormToolData> Default for RadioButtonsBuildData<FD> {
+    fn default() -> Self {
+        RadioButtonsBuildData {
+            name: String::default(),
+            label: None,
+            dynamic_options: None,
+            static_options: Vec::new(),
+            options: Signal::default(),
+            layout: OptionLayout::default(),
+            other_option: None,
+        }
+    }
+}
+impl<FD: FormToolData> Clone for RadioButtonsBuildData<FD> {
+    fn clone(&self) -> Self {
+        RadioButtonsBuildData {
+            name: self.name.clone(),
+            label: self.label.clone(),
+            dynamic_options: self.dynamic_options.clone(),
+            static_options: self.static_options.clone(),
+            options: self.options,
+            layout: self.layout,
+            other_option: self.other_option.clone(),
+        }
+    }
+}
+
+/// Data used for the radio buttons control.
+#[derive(Debug, Clone, Default)]
 pub struct RadioButtonsData {
     pub name: String,
     pub label: Option<String>,
-    /// The options for the select.
+    /// The options for the radio buttons.
     ///
-    /// The first value is the string to display, the second is the value.
-    pub options: Vec<(String, String)>,
+    /// Each [`RadioOption`] converts into its `(display, value)` pair form
+    /// with `Into`.
+    pub options: Signal<Vec<RadioOption>>,
+    pub layout: OptionLayout,
+    /// The label of the "other" option, if there is one.
+    ///
+    /// When it is picked, the user can type their own value.
+    pub other_option: Option<String>,
 }
 
-impl<FD: FormToolData> ControlData<FD> for RadioButtonsData {
+impl<FD: FormToolData> ControlData<FD> for RadioButtonsBuildData<FD> {
     type ReturnType = String;
 
     fn render_control<FS: FormStyle>(
         fs: &FS,
-        _fd: RwSignal<FD>,
+        fd: RwSignal<FD>,
         control: ControlRenderData<FS, Self>,
         value_getter: Signal<Self::ReturnType>,
         value_setter: SignalSetter<Self::ReturnType>,
         validation_state: Signal<ValidationState>,
     ) -> AnyView {
-        fs.radio_buttons(control, value_getter, value_setter, validation_state)
+        let mut options = control
+            .data
+            .dynamic_options
+            .as_ref()
+            .map(|d| {
+                let d = d.clone();
+                Signal::derive(move || d(fd))
+            })
+            .unwrap_or(control.data.options);
+        // the options added one at a time follow the signal's options
+        if !control.data.static_options.is_empty() {
+            let static_options = control.data.static_options.clone();
+            let signal_options = options;
+            options = Signal::derive(move || {
+                let mut options = signal_options.get();
+                options.extend(static_options.iter().cloned());
+                options
+            });
+        }
+
+        let new_control = ControlRenderData {
+            styles: control.styles.clone(),
+            data: RadioButtonsData {
+                name: control.data.name.clone(),
+                label: control.data.label.clone(),
+                options,
+                layout: control.data.layout,
+                other_option: control.data.other_option.clone(),
+            },
+        };
+
+        fs.radio_buttons(new_control, value_getter, value_setter, validation_state)
     }
 }
-impl<FD: FormToolData> ValidatedControlData<FD> for RadioButtonsData {}
+impl<FD: FormToolData> ValidatedControlData<FD> for RadioButtonsBuildData<FD> {}
 
 impl<FD: FormToolData> FormBuilder<FD> {
     /// Builds a radio buttons control and adds it to the form.
     pub fn radio_buttons<FDT: Clone + PartialEq + 'static>(
         self,
-        builder: impl BuilderFn<ControlBuilder<FD, RadioButtonsData, FDT>>,
+        builder: impl BuilderFn<ControlBuilder<FD, RadioButtonsBuildData<FD>, FDT>>,
     ) -> Self {
         self.new_control(builder)
     }
 
     /// Builds a radio buttons control using the form's context and adds it to
     /// the form.
+    ///
+    /// This can be used to get the options from the context.
     pub fn radio_buttons_cx<FDT: Clone + PartialEq + 'static>(
         self,
-        builder: impl BuilderCxFn<ControlBuilder<FD, RadioButtonsData, FDT>, FD::Context>,
+        builder: impl BuilderCxFn<ControlBuilder<FD, RadioButtonsBuildData<FD>, FDT>, FD::Context>,
     ) -> Self {
         self.new_control_cx(builder)
     }
 }
 
-impl<FD: FormToolData, FDT> ControlBuilder<FD, RadioButtonsData, FDT> {
+impl<FD: FormToolData, FDT> ControlBuilder<FD, RadioButtonsBuildData<FD>, FDT> {
     /// Sets the name of the radio button inputs.
     ///
     /// This is used for the html element's "name" attribute.
@@ -71,20 +227,38 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, RadioButtonsData, FDT> {
         self
     }
 
-    /// Adds the option to the radio button group.
-    pub fn with_option(mut self, option: impl ToString) -> Self {
-        self.data
-            .options
-            .push((option.to_string(), option.to_string()));
+    /// Puts all the radio buttons on one line.
+    pub fn inline(mut self) -> Self {
+        self.data.layout = OptionLayout::Inline;
         self
     }
 
+    /// Puts each radio button on its own line.
+    ///
+    /// This is the default.
+    pub fn stacked(mut self) -> Self {
+        self.data.layout = OptionLayout::Stacked;
+        self
+    }
+
+    /// Adds the option to the radio button group.
+    pub fn with_option(self, option: impl ToString) -> Self {
+        self.with_radio_option(RadioOption::new(option))
+    }
+
     /// Adds the option to the radio button group, specifying a different
     /// value than what is displayed.
-    pub fn with_option_valued(mut self, display: impl ToString, value: impl ToString) -> Self {
-        self.data
-            .options
-            .push((display.to_string(), value.to_string()));
+    pub fn with_option_valued(self, display: impl ToString, value: impl ToString) -> Self {
+        self.with_radio_option(RadioOption::valued(display, value))
+    }
+
+    /// Adds the [`RadioOption`] to the radio button group, which can have
+    /// a description or be disabled.
+    ///
+    /// Options added this way are shown after any options from a signal or
+    /// derived signal, which keep updating.
+    pub fn with_radio_option(mut self, option: RadioOption) -> Self {
+        self.data.static_options.push(option);
         self
     }
 
@@ -92,9 +266,7 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, RadioButtonsData, FDT> {
     /// group.
     pub fn with_options(mut self, options: impl Iterator<Item = impl ToString>) -> Self {
         for option in options {
-            self.data
-                .options
-                .push((option.to_string(), option.to_string()));
+            self = self.with_option(option);
         }
         self
     }
@@ -105,11 +277,151 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, RadioButtonsData, FDT> {
         mut self,
         options: impl Iterator<Item = (impl ToString, impl ToString)>,
     ) -> Self {
-        for option in options {
-            self.data
-                .options
-                .push((option.0.to_string(), option.1.to_string()));
+        for (display, value) in options {
+            self = self.with_option_valued(display, value);
         }
         self
     }
+
+    /// Sets the options from the provided signal.
+    ///
+    /// This will overwrite any pervious options signal setting. Options
+    /// added one at a time are still shown after these.
+    pub fn with_options_signal(mut self, options: Signal<Vec<String>>) -> Self {
+        // clear dynamic option
+        self.data.dynamic_options = None;
+
+        let options = move || {
+            options
+                .get()
+                .into_iter()
+                .map(RadioOption::new)
+                .collect::<Vec<_>>()
+        };
+        self.data.options = Signal::derive(options);
+        self
+    }
+
+    /// Sets the options to the (display_string, value) pairs from the
+    /// provided signal.
+    ///
+    /// This will overwrite any pervious options signal setting. Options
+    /// added one at a time are still shown after these.
+    pub fn with_options_valued_signal(mut self, options: Signal<Vec<(String, String)>>) -> Self {
+        // clear dynamic option
+        self.data.dynamic_options = None;
+
+        let options = move || {
+            options
+                .get()
+                .into_iter()
+                .map(RadioOption::from)
+                .collect::<Vec<_>>()
+        };
+        self.data.options = Signal::derive(options);
+        self
+    }
+
+    /// Sets the options to the [`RadioOption`]s from the provided signal.
+    ///
+    /// This will overwrite any pervious options signal setting. Options
+    /// added one at a time are still shown after these.
+    pub fn with_radio_options_signal(mut self, options: Signal<Vec<RadioOption>>) -> Self {
+        // clear dynamic option
+        self.data.dynamic_options = None;
+
+        self.data.options = options;
+        self
+    }
+
+    /// Sets the options to the given derived signal.
+    ///
+    /// This will overwrite any pervious options signal setting. Options
+    /// added one at a time are still shown after these.
+    pub fn with_dynamic_options(
+        mut self,
+        derived_signal: impl Fn(RwSignal<FD>) -> Vec<String> + Send + Sync + 'static,
+    ) -> Self {
+        let derived_signal = move |fd| {
+            derived_signal(fd)
+                .into_iter()
+                .map(RadioOption::new)
+                .collect::<Vec<_>>()
+        };
+        self.data.dynamic_options = Some(Arc::new(derived_signal));
+        self
+    }
+
+    /// Sets the options to the (display_string, value) pairs from the
+    /// provided derived signal.
+    ///
+    /// This will overwrite any pervious options signal setting. Options
+    /// added one at a time are still shown after these.
+    pub fn with_dynamic_options_valued(
+        mut self,
+        derived_signal: impl Fn(RwSignal<FD>) -> Vec<(String, String)> + Send + Sync + 'static,
+    ) -> Self {
+        let derived_signal = move |fd| {
+            derived_signal(fd)
+                .into_iter()
+                .map(RadioOption::from)
+                .collect::<Vec<_>>()
+        };
+        self.data.dynamic_options = Some(Arc::new(derived_signal));
+        self
+    }
+
+    /// Sets the options to the [`RadioOption`]s from the provided derived
+    /// signal.
+    ///
+    /// This will overwrite any pervious options signal setting. Options
+    /// added one at a time are still shown after these.
+    pub fn with_dynamic_radio_options(
+        mut self,
+        derived_signal: impl Fn(RwSignal<FD>) -> Vec<RadioOption> + Send + Sync + 'static,
+    ) -> Self {
+        self.data.dynamic_options = Some(Arc::new(derived_signal));
+        self
+    }
+}
+
+impl<FD: FormToolData, FDT: 'static> ControlBuilder<FD, RadioButtonsBuildData<FD>, FDT> {
+    /// Sets the options to the variants of the [`FormEnum`], and sets the
+    /// parse functions to parse the picked variant.
+    ///
+    /// The field can be the enum or an `Option` of it, which is `None` when
+    /// nothing is picked.
+    ///
+    /// This will overwrite any pervious options setting, including options
+    /// added one at a time.
+    pub fn with_enum_options<E: FormEnum>(mut self) -> Self
+    where
+        FDT: EnumField<E>,
+    {
+        self.data.static_options.clear();
+        let options = E::variants()
+            .iter()
+            .map(|variant| RadioOption::valued(variant.label(), variant.value()))
+            .collect();
+        self = self.with_radio_options_signal(Signal::stored(options));
+
+        self.parse_fn = Some(Box::new(|value: String| FDT::from_enum_value(&value)));
+        self.unparse_fn = Some(Box::new(|field: FDT| field.to_enum_value()));
+        self
+    }
+}
+
+impl<FD, T> ControlBuilder<FD, RadioButtonsBuildData<FD>, Choice<T>>
+where
+    FD: FormToolData,
+    T: FromStr + ToString + 'static,
+    <T as FromStr>::Err: ToString,
+{
+    /// Adds an "other" option with the given label, which shows a text
+    /// input named `{name}_other` for a [`Choice::Other`] when picked.
+    pub fn with_other_option(self, label: impl ToString) -> Self {
+        let mut builder = self.parse_choice();
+        builder.data.other_option = Some(label.to_string());
+        builder
+    }
 }
@@ -0,0 +1,238 @@
+use super::{
+    BuilderCxFn, BuilderFn, ControlBuilder, ControlData, ControlRenderData, ValidatedControlData,
+    ValidationState,
+};
+use crate::{form::FormToolData, form_builder::FormBuilder, styles::FormStyle};
+use leptos::{
+    prelude::{AnyView, RwSignal, Signal},
+    reactive::wrappers::write::SignalSetter,
+};
+use std::{ops::RangeInclusive, str::FromStr};
+
+/// A field type that holds the low and high values of a range slider.
+///
+/// This is implemented for `(T, T)` and [`RangeInclusive<T>`].
+pub trait SliderRange<T>: Sized {
+    /// Creates the range from the low and high values.
+    fn from_bounds(low: T, high: T) -> Self;
+
+    /// Gets the low and high values of the range.
+    fn bounds(&self) -> (&T, &T);
+}
+
+impl<T> SliderRange<T> for (T, T) {
+    fn from_bounds(low: T, high: T) -> Self {
+        (low, high)
+    }
+
+    fn bounds(&self) -> (&T, &T) {
+        (&self.0, &self.1)
+    }
+}
+
+impl<T> SliderRange<T> for RangeInclusive<T> {
+    fn from_bounds(low: T, high: T) -> Self {
+        low..=high
+    }
+
+    fn bounds(&self) -> (&T, &T) {
+        (self.start(), self.end())
+    }
+}
+
+/// Data used for the range slider control.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct RangeSliderData {
+    pub name: String,
+    pub label: Option<String>,
+    pub step: Option<Signal<String>>,
+    pub min: Option<Signal<String>>,
+    pub max: Option<Signal<String>>,
+}
+
+impl<FD: FormToolData> ControlData<FD> for RangeSliderData {
+    /// The (low, high) values, as strings to support integers or decimal
+    /// point types.
+    type ReturnType = (String, String);
+
+    fn render_control<FS: FormStyle>(
+        fs: &FS,
+        _fd: RwSignal<FD>,
+        control: ControlRenderData<FS, Self>,
+        value_getter: Signal<Self::ReturnType>,
+        value_setter: SignalSetter<Self::ReturnType>,
+        validation_state: Signal<ValidationState>,
+    ) -> AnyView {
+        fs.range_slider(control, value_getter, value_setter, validation_state)
+    }
+}
+impl<FD: FormToolData> ValidatedControlData<FD> for RangeSliderData {}
+
+impl<FD: FormToolData> FormBuilder<FD> {
+    /// Builds a range slider control and adds it to the form.
+    ///
+    /// A range slider has two handles for picking a low and a high value.
+    pub fn range_slider<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderFn<ControlBuilder<FD, RangeSliderData, FDT>>,
+    ) -> Self {
+        self.new_control(builder)
+    }
+
+    /// Bulids a range slider control using the form's context and adds it
+    /// to the form.
+    pub fn range_slider_cx<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderCxFn<ControlBuilder<FD, RangeSliderData, FDT>, FD::Context>,
+    ) -> Self {
+        self.new_control_cx(builder)
+    }
+}
+
+impl<FD: FormToolData, FDT> ControlBuilder<FD, RangeSliderData, FDT> {
+    /// Sets the name of the range slider.
+    ///
+    /// This is used for the html elements' "name" attributes, with `_low`
+    /// and `_high` added for the two handles.
+    /// In forms, the name attribute is the key that the data is sent
+    /// with.
+    pub fn named(mut self, control_name: impl ToString) -> Self {
+        self.data.name = control_name.to_string();
+        self
+    }
+
+    /// Sets the label for the range slider.
+    pub fn labeled(mut self, label: impl ToString) -> Self {
+        self.data.label = Some(label.to_string());
+        self
+    }
+
+    /// Sets the step ammount.
+    pub fn step(mut self, step: impl ToString) -> Self {
+        self.data.step = Some(Signal::stored(step.to_string()));
+        self
+    }
+
+    /// Sets the step ammount to a signal.
+    pub fn step_signal(mut self, step: Signal<String>) -> Self {
+        self.data.step = Some(step);
+        self
+    }
+
+    /// Sets the minimum value for the range slider.
+    pub fn min(mut self, min: impl ToString) -> Self {
+        self.data.min = Some(Signal::stored(min.to_string()));
+        self
+    }
+
+    /// Sets the minimum value for the range slider to a signal.
+    pub fn min_signal(mut self, min: Signal<String>) -> Self {
+        self.data.min = Some(min);
+        self
+    }
+
+    /// Sets the maximum value for the range slider.
+    pub fn max(mut self, max: impl ToString) -> Self {
+        self.data.max = Some(Signal::stored(max.to_string()));
+        self
+    }
+
+    /// Sets the maximum value for the range slider to a signal.
+    pub fn max_signal(mut self, max: Signal<String>) -> Self {
+        self.data.max = Some(max);
+        self
+    }
+
+    /// Sets the parse functions to use the [`FromStr`] and [`ToString`]
+    /// traits on the low and high values, and the [`SliderRange`] trait to
+    /// build the field.
+    ///
+    /// If the low value is greater than the high value, they are swapped so
+    /// that low ≤ high.
+    ///
+    /// The parse and unparse functions define how to turn what the user
+    /// picks in the form into what is stored in the form data struct and
+    /// vice versa.
+    pub fn parse_range<T>(mut self) -> Self
+    where
+        FDT: SliderRange<T>,
+        T: FromStr + ToString + PartialOrd,
+        <T as FromStr>::Err: ToString,
+    {
+        self.parse_fn = Some(Box::new(|(low, high): (String, String)| {
+            let low = low.parse::<T>().map_err(|e| e.to_string())?;
+            let high = high.parse::<T>().map_err(|e| e.to_string())?;
+            if low > high {
+                Ok(FDT::from_bounds(high, low))
+            } else {
+                Ok(FDT::from_bounds(low, high))
+            }
+        }));
+        self.unparse_fn = Some(Box::new(|field: FDT| {
+            let (low, high) = field.bounds();
+            (low.to_string(), high.to_string())
+        }));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::styles::GridFormStyle;
+
+    #[derive(Clone)]
+    struct Search;
+    impl FormToolData for Search {
+        type Style = GridFormStyle;
+        type Context = ();
+
+        fn build_form(fb: FormBuilder<Self>) -> FormBuilder<Self> {
+            fb
+        }
+    }
+
+    fn parse<FDT: SliderRange<f64>>(low: &str, high: &str) -> Result<FDT, String> {
+        let builder = ControlBuilder::<Search, RangeSliderData, FDT>::new(Default::default())
+            .parse_range::<f64>();
+        (builder
+            .parse_fn
+            .expect("parse_range sets the parse function"))((
+            low.to_string(),
+            high.to_string(),
+        ))
+    }
+
+    fn unparse<FDT: SliderRange<f64>>(field: FDT) -> (String, String) {
+        let builder = ControlBuilder::<Search, RangeSliderData, FDT>::new(Default::default())
+            .parse_range::<f64>();
+        (builder
+            .unparse_fn
+            .expect("parse_range sets the unparse function"))(field)
+    }
+
+    #[test]
+    fn tuples_keep_low_before_high() {
+        assert_eq!(parse::<(f64, f64)>("1", "5"), Ok((1.0, 5.0)));
+        assert_eq!(parse::<(f64, f64)>("5", "1"), Ok((1.0, 5.0)));
+        assert_eq!(parse::<(f64, f64)>("2.5", "2.5"), Ok((2.5, 2.5)));
+        assert_eq!(
+            unparse((1.5, 5.0)),
+            (String::from("1.5"), String::from("5"))
+        );
+    }
+
+    #[test]
+    fn ranges_keep_low_before_high() {
+        assert_eq!(parse::<RangeInclusive<f64>>("1", "5"), Ok(1.0..=5.0));
+        assert_eq!(parse::<RangeInclusive<f64>>("5", "1"), Ok(1.0..=5.0));
+        assert_eq!(parse::<RangeInclusive<f64>>("-3", "-7"), Ok(-7.0..=-3.0));
+        assert_eq!(unparse(1.0..=5.0), (String::from("1"), String::from("5")));
+    }
+
+    #[test]
+    fn bounds_must_be_numbers() {
+        assert!(parse::<(f64, f64)>("low", "5").is_err());
+        assert!(parse::<RangeInclusive<f64>>("1", "").is_err());
+    }
+}
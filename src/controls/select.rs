@@ -1,16 +1,129 @@
 use super::{
-    BuilderCxFn, BuilderFn, ControlBuilder, ControlData, ControlRenderData, ValidatedControlData,
-    ValidationState,
+    other_text, BuilderCxFn, BuilderFn, Choice, ControlBuilder, ControlData, ControlRenderData,
+    ValidatedControlData, ValidationState,
+};
+use crate::{
+    form::FormToolData, form_builder::FormBuilder, form_enum::EnumField, styles::FormStyle,
+    FormEnum,
 };
-use crate::{form::FormToolData, form_builder::FormBuilder, styles::FormStyle};
 use leptos::{
-    prelude::{AnyView, Get, RwSignal, Signal},
+    prelude::{
+        AnyView, Effect, Get, GetUntracked, GetValue, RwSignal, Set, SetValue, Signal, StoredValue,
+    },
     reactive::wrappers::write::SignalSetter,
+    task,
 };
-use std::sync::Arc;
+use std::{fmt::Display, future::Future, pin::Pin, str::FromStr, sync::Arc};
+
+/// What to do with a select's value when it is no longer one of the
+/// options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum InvalidReset {
+    /// Clear the value, setting it to an empty string.
+    Clear,
+    /// Set the value to the first option that isn't disabled.
+    First,
+}
+impl InvalidReset {
+    /// Gets the value to change `value` to now that the select has
+    /// `options`, or `None` if it should be kept.
+    ///
+    /// Values that are one of the options, "other" values and, if
+    /// `allows_blank`, empty values are kept.
+    fn reset(self, value: &str, options: &[SelectOption], allows_blank: bool) -> Option<String> {
+        if (allows_blank && value.is_empty())
+            || other_text(value).is_some()
+            || options.iter().any(|option| option.value == value)
+        {
+            return None;
+        }
+
+        let new_value = match self {
+            InvalidReset::Clear => String::new(),
+            InvalidReset::First => options
+                .iter()
+                .find(|option| !option.disabled)
+                .map(|option| option.value.clone())
+                .unwrap_or_default(),
+        };
+        (new_value != value).then_some(new_value)
+    }
+}
+
+/// An option for a select.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct SelectOption {
+    /// The string to display.
+    pub display: String,
+    /// The value that is picked.
+    pub value: String,
+    /// The group that the option is shown under, if any.
+    pub group: Option<String>,
+    /// Whether the option is shown but can't be picked.
+    pub disabled: bool,
+}
+impl SelectOption {
+    /// Creates an option that displays its value.
+    pub fn new(value: impl ToString) -> Self {
+        let value = value.to_string();
+        SelectOption {
+            display: value.clone(),
+            value,
+            ..Default::default()
+        }
+    }
+
+    /// Creates an option with a different value than what is displayed.
+    pub fn valued(display: impl ToString, value: impl ToString) -> Self {
+        SelectOption {
+            display: display.to_string(),
+            value: value.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the group that the option is shown under.
+    pub fn group(mut self, group: impl ToString) -> Self {
+        self.group = Some(group.to_string());
+        self
+    }
+
+    /// Makes the option unpickable.
+    pub fn disabled(mut self) -> Self {
+        self.disabled = true;
+        self
+    }
+
+    /// Groups the options by their group, in the order each group first
+    /// appears.
+    ///
+    /// Options without a group are kept in their own "group" of `None`.
+    pub fn grouped(options: Vec<SelectOption>) -> Vec<(Option<String>, Vec<SelectOption>)> {
+        let mut groups: Vec<(Option<String>, Vec<SelectOption>)> = Vec::new();
+        for option in options {
+            match groups.iter_mut().find(|(group, _)| *group == option.group) {
+                Some((_, group_options)) => group_options.push(option),
+                None => groups.push((option.group.clone(), vec![option])),
+            }
+        }
+        groups
+    }
+}
+impl From<(String, String)> for SelectOption {
+    fn from((display, value): (String, String)) -> Self {
+        SelectOption::valued(display, value)
+    }
+}
+impl From<String> for SelectOption {
+    fn from(value: String) -> Self {
+        SelectOption::new(value)
+    }
+}
 
 type DynamicOptionsGetter<FD> =
-    Arc<dyn Fn(RwSignal<FD>) -> Vec<(String, String)> + Send + Sync + 'static>;
+    Arc<dyn Fn(RwSignal<FD>) -> Vec<SelectOption> + Send + Sync + 'static>;
+type OptionsFuture = Pin<Box<dyn Future<Output = Result<Vec<SelectOption>, String>>>>;
+type OptionsLoader<FD> = Arc<dyn Fn(RwSignal<FD>) -> OptionsFuture + Send + Sync + 'static>;
 /// Data used for building the select control.
 pub struct SelectBuildData<FD: FormToolData> {
     pub name: String,
@@ -20,12 +133,29 @@ pub struct SelectBuildData<FD: FormToolData> {
     /// This is just a temp value for building, and should not be used
     /// directly
     dynamic_options: Option<DynamicOptionsGetter<FD>>,
-    /// The options for the select.
+    /// The function that loads the options for the select.
     ///
-    /// The first value is the string to display, the second is the value.
-    pub options: Signal<Vec<(String, String)>>,
+    /// This is just a temp value for building, and should not be used
+    /// directly
+    options_loader: Option<OptionsLoader<FD>>,
+    /// The options for the select.
+    pub options: Signal<Vec<SelectOption>>,
+    /// Whether the options are being loaded.
+    pub loading: Signal<bool>,
+    /// The error from loading the options, if there was one.
+    pub error: Signal<Option<String>>,
     /// The display text for the blank option, if there is one.
     pub blank_option: Option<String>,
+    /// What to do with the value when the options change and no longer
+    /// contain it, if anything.
+    pub reset_when_invalid: Option<InvalidReset>,
+    /// Whether the select is rendered with
+    /// [`FormStyle::searchable_select`].
+    pub searchable: bool,
+    /// The label of the "other" option, if there is one.
+    ///
+    /// When it is picked, the user can type their own value.
+    pub other_option: Option<String>,
 }
 impl<FD: FormToolData> Default for SelectBuildData<FD> {
     fn default() -> Self {
@@ -33,8 +163,14 @@ impl<FD: FormToolData> Default for SelectBuildData<FD> {
             name: String::default(),
             label: None,
             dynamic_options: None,
+            options_loader: None,
             options: Signal::default(),
+            loading: Signal::default(),
+            error: Signal::default(),
             blank_option: None,
+            reset_when_invalid: None,
+            searchable: false,
+            other_option: None,
         }
     }
 }
@@ -44,8 +180,14 @@ impl<FD: FormToolData> Clone for SelectBuildData<FD> {
             name: self.name.clone(),
             label: self.label.clone(),
             dynamic_options: self.dynamic_options.clone(),
+            options_loader: self.options_loader.clone(),
             options: self.options,
+            loading: self.loading,
+            error: self.error,
             blank_option: self.blank_option.clone(),
+            reset_when_invalid: self.reset_when_invalid,
+            searchable: self.searchable,
+            other_option: self.other_option.clone(),
         }
     }
 }
@@ -56,11 +198,17 @@ pub struct SelectData {
     pub name: String,
     pub label: Option<String>,
     /// The options for the select.
-    ///
-    /// The first value is the string to display, the second is the value.
-    pub options: Signal<Vec<(String, String)>>,
+    pub options: Signal<Vec<SelectOption>>,
+    /// Whether the options are being loaded.
+    pub loading: Signal<bool>,
+    /// The error from loading the options, if there was one.
+    pub error: Signal<Option<String>>,
     /// The display text for the blank option, if there is one.
     pub blank_option: Option<String>,
+    /// The label of the "other" option, if there is one.
+    ///
+    /// When it is picked, the user can type their own value.
+    pub other_option: Option<String>,
 }
 
 impl<FD: FormToolData> ControlData<FD> for SelectBuildData<FD> {
@@ -74,7 +222,7 @@ impl<FD: FormToolData> ControlData<FD> for SelectBuildData<FD> {
         value_setter: SignalSetter<Self::ReturnType>,
         validation_state: Signal<ValidationState>,
     ) -> AnyView {
-        let options = control
+        let mut options = control
             .data
             .dynamic_options
             .as_ref()
@@ -83,6 +231,60 @@ impl<FD: FormToolData> ControlData<FD> for SelectBuildData<FD> {
                 Signal::derive(move || d(fd))
             })
             .unwrap_or(control.data.options);
+        let mut loading = control.data.loading;
+        let mut error = control.data.error;
+
+        if let Some(loader) = control.data.options_loader.clone() {
+            let loaded = RwSignal::new(Vec::new());
+            let is_loading = RwSignal::new(true);
+            let load_error = RwSignal::new(None);
+            // the latest load, so outdated responses can be dropped
+            let latest = StoredValue::new(0_usize);
+            // the fields that the loader reads from fd are tracked, so the
+            // options are reloaded when they change
+            Effect::new(move |_| {
+                let future = loader(fd);
+                let version = latest.get_value() + 1;
+                latest.set_value(version);
+                is_loading.set(true);
+                task::spawn_local(async move {
+                    let result = future.await;
+                    if latest.get_value() != version {
+                        return;
+                    }
+                    match result {
+                        Ok(new_options) => {
+                            loaded.set(new_options);
+                            load_error.set(None);
+                        }
+                        Err(e) => load_error.set(Some(e)),
+                    }
+                    is_loading.set(false);
+                });
+            });
+            options = loaded.into();
+            loading = is_loading.into();
+            error = load_error.into();
+        }
+
+        if let Some(reset) = control.data.reset_when_invalid {
+            let allows_blank = control.data.blank_option.is_some();
+            Effect::watch(
+                move || options.get(),
+                move |options, _, _| {
+                    // wait for the options to load before resetting
+                    if loading.get_untracked() {
+                        return;
+                    }
+                    let value = value_getter.get_untracked();
+                    // setting the value also re-runs the validation
+                    if let Some(new_value) = reset.reset(&value, options, allows_blank) {
+                        value_setter.set(new_value);
+                    }
+                },
+                true,
+            );
+        }
 
         let new_control = ControlRenderData {
             styles: control.styles.clone(),
@@ -90,11 +292,18 @@ impl<FD: FormToolData> ControlData<FD> for SelectBuildData<FD> {
                 name: control.data.name.clone(),
                 label: control.data.label.clone(),
                 options,
+                loading,
+                error,
                 blank_option: control.data.blank_option.clone(),
+                other_option: control.data.other_option.clone(),
             },
         };
 
-        fs.select(new_control, value_getter, value_setter, validation_state)
+        if control.data.searchable {
+            fs.searchable_select(new_control, value_getter, value_setter, validation_state)
+        } else {
+            fs.select(new_control, value_getter, value_setter, validation_state)
+        }
     }
 }
 impl<FD: FormToolData> ValidatedControlData<FD> for SelectBuildData<FD> {}
@@ -141,8 +350,9 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, SelectBuildData<FD>, FDT> {
     pub fn with_options(mut self, options: impl Iterator<Item = impl ToString>) -> Self {
         // clear dynamic option
         self.data.dynamic_options = None;
+        self.data.options_loader = None;
 
-        let options = options.map(|v| (v.to_string(), v.to_string())).collect();
+        let options = options.map(SelectOption::new).collect();
         self.data.options = Signal::stored(options);
         self
     }
@@ -157,26 +367,39 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, SelectBuildData<FD>, FDT> {
     ) -> Self {
         // clear dynamic option
         self.data.dynamic_options = None;
+        self.data.options_loader = None;
 
-        let options = options
-            .map(|(d, v)| (d.to_string(), v.to_string()))
-            .collect();
+        let options = options.map(|(d, v)| SelectOption::valued(d, v)).collect();
         self.data.options = Signal::stored(options);
         self
     }
 
+    /// Sets the options to the [`SelectOption`]s from the provided
+    /// iterator, which can be grouped or disabled.
+    ///
+    /// This will overwrite any pervious options setting.
+    pub fn with_select_options(mut self, options: impl IntoIterator<Item = SelectOption>) -> Self {
+        // clear dynamic option
+        self.data.dynamic_options = None;
+        self.data.options_loader = None;
+
+        self.data.options = Signal::stored(options.into_iter().collect());
+        self
+    }
+
     /// Sets the options from the provided signal.
     ///
     /// This will overwrite any pervious options setting.
     pub fn with_options_signal(mut self, options: Signal<Vec<String>>) -> Self {
         // clear dynamic option
         self.data.dynamic_options = None;
+        self.data.options_loader = None;
 
         let options = move || {
             options
                 .get()
                 .into_iter()
-                .map(|v| (v.clone(), v))
+                .map(SelectOption::new)
                 .collect::<Vec<_>>()
         };
 
@@ -191,6 +414,26 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, SelectBuildData<FD>, FDT> {
     pub fn with_options_valued_signal(mut self, options: Signal<Vec<(String, String)>>) -> Self {
         // clear dynamic option
         self.data.dynamic_options = None;
+        self.data.options_loader = None;
+
+        let options = move || {
+            options
+                .get()
+                .into_iter()
+                .map(SelectOption::from)
+                .collect::<Vec<_>>()
+        };
+        self.data.options = Signal::derive(options);
+        self
+    }
+
+    /// Sets the options to the [`SelectOption`]s from the provided signal.
+    ///
+    /// This will overwrite any pervious options setting.
+    pub fn with_select_options_signal(mut self, options: Signal<Vec<SelectOption>>) -> Self {
+        // clear dynamic option
+        self.data.dynamic_options = None;
+        self.data.options_loader = None;
 
         self.data.options = options;
         self
@@ -206,9 +449,10 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, SelectBuildData<FD>, FDT> {
         let derived_signal = move |fd| {
             derived_signal(fd)
                 .into_iter()
-                .map(|v| (v.clone(), v))
+                .map(SelectOption::new)
                 .collect::<Vec<_>>()
         };
+        self.data.options_loader = None;
         self.data.dynamic_options = Some(Arc::new(derived_signal));
         self
     }
@@ -221,10 +465,121 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, SelectBuildData<FD>, FDT> {
         mut self,
         derived_signal: impl Fn(RwSignal<FD>) -> Vec<(String, String)> + Send + Sync + 'static,
     ) -> Self {
+        let derived_signal = move |fd| {
+            derived_signal(fd)
+                .into_iter()
+                .map(SelectOption::from)
+                .collect::<Vec<_>>()
+        };
+        self.data.options_loader = None;
+        self.data.dynamic_options = Some(Arc::new(derived_signal));
+        self
+    }
+
+    /// Sets the options to the [`SelectOption`]s from the provided derived
+    /// signal.
+    ///
+    /// This will overwrite any pervious options setting.
+    pub fn with_dynamic_select_options(
+        mut self,
+        derived_signal: impl Fn(RwSignal<FD>) -> Vec<SelectOption> + Send + Sync + 'static,
+    ) -> Self {
+        self.data.options_loader = None;
         self.data.dynamic_options = Some(Arc::new(derived_signal));
         self
     }
 
+    /// Sets the options to the ones loaded by the given function, like a
+    /// server function.
+    ///
+    /// Any fields read from the form data before the future is returned are
+    /// tracked, and the options are reloaded when they change. The loading
+    /// and error states are passed to the [`FormStyle`].
+    ///
+    /// This will overwrite any pervious options setting.
+    pub fn with_options_loader<Fut, O, E>(
+        mut self,
+        loader: impl Fn(RwSignal<FD>) -> Fut + Send + Sync + 'static,
+    ) -> Self
+    where
+        Fut: Future<Output = Result<Vec<O>, E>> + 'static,
+        O: Into<SelectOption>,
+        E: Display,
+    {
+        let loader = move |fd| {
+            let future = loader(fd);
+            Box::pin(async move {
+                match future.await {
+                    Ok(options) => Ok(options.into_iter().map(Into::into).collect()),
+                    Err(e) => Err(e.to_string()),
+                }
+            }) as OptionsFuture
+        };
+        self.data.dynamic_options = None;
+        self.data.options_loader = Some(Arc::new(loader));
+        self
+    }
+
+    /// Sets the options to the ones from the given resource, like a
+    /// [`Resource`](leptos::prelude::Resource) or
+    /// [`LocalResource`](leptos::prelude::LocalResource).
+    ///
+    /// The loading and error states are passed to the [`FormStyle`].
+    ///
+    /// This will overwrite any pervious options setting.
+    pub fn with_options_resource<R, O, E>(mut self, resource: R) -> Self
+    where
+        R: Get<Value = Option<Result<Vec<O>, E>>> + Clone + Send + Sync + 'static,
+        O: Into<SelectOption>,
+        E: Display,
+    {
+        // clear dynamic option
+        self.data.dynamic_options = None;
+        self.data.options_loader = None;
+
+        let options_resource = resource.clone();
+        self.data.options = Signal::derive(move || match options_resource.get() {
+            Some(Ok(options)) => options.into_iter().map(Into::into).collect(),
+            _ => Vec::new(),
+        });
+        let loading_resource = resource.clone();
+        self.data.loading = Signal::derive(move || loading_resource.get().is_none());
+        self.data.error = Signal::derive(move || match resource.get() {
+            Some(Err(e)) => Some(e.to_string()),
+            _ => None,
+        });
+        self
+    }
+
+    /// Makes the select searchable, for long lists of options.
+    ///
+    /// This uses [`FormStyle::searchable_select`], which falls back to a
+    /// plain select if the style doesn't have a searchable one.
+    pub fn searchable(mut self) -> Self {
+        self.data.searchable = true;
+        self
+    }
+
+    /// Clears the value when the options change and no longer contain it.
+    ///
+    /// This is useful for selects that depend on other fields through
+    /// [`with_dynamic_options`](Self::with_dynamic_options), like a state
+    /// select that depends on the country. The cleared value goes through
+    /// the parse function and is validated like a value the user picked.
+    pub fn reset_when_invalid(mut self) -> Self {
+        self.data.reset_when_invalid = Some(InvalidReset::Clear);
+        self
+    }
+
+    /// Sets the value to the first option when the options change and no
+    /// longer contain it.
+    ///
+    /// See [`reset_when_invalid`](Self::reset_when_invalid).
+    pub fn select_first_when_invalid(mut self) -> Self {
+        self.data.reset_when_invalid = Some(InvalidReset::First);
+        self
+    }
+
     /// Adds a blank option as the first option for the select.
     pub fn with_blank_option(mut self) -> Self {
         self.data.blank_option = Some(String::new());
@@ -238,3 +593,103 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, SelectBuildData<FD>, FDT> {
         self
     }
 }
+
+impl<FD: FormToolData, FDT: 'static> ControlBuilder<FD, SelectBuildData<FD>, FDT> {
+    /// Sets the options to the variants of the [`FormEnum`], and sets the
+    /// parse functions to parse the picked variant.
+    ///
+    /// The field can be the enum or an `Option` of it. For an `Option`, a
+    /// blank option is added if there isn't one already, which is parsed as
+    /// `None`.
+    ///
+    /// This will overwrite any pervious options setting.
+    pub fn with_enum_options<E: FormEnum>(mut self) -> Self
+    where
+        FDT: EnumField<E>,
+    {
+        if FDT::OPTIONAL && self.data.blank_option.is_none() {
+            self.data.blank_option = Some(String::new());
+        }
+        let options = E::variants()
+            .iter()
+            .map(|variant| SelectOption::valued(variant.label(), variant.value()));
+        self = self.with_select_options(options);
+
+        self.parse_fn = Some(Box::new(|value: String| FDT::from_enum_value(&value)));
+        self.unparse_fn = Some(Box::new(|field: FDT| field.to_enum_value()));
+        self
+    }
+}
+
+impl<FD, T> ControlBuilder<FD, SelectBuildData<FD>, Choice<T>>
+where
+    FD: FormToolData,
+    T: FromStr + ToString + 'static,
+    <T as FromStr>::Err: ToString,
+{
+    /// Adds an "other" option with the given label to the select, which lets
+    /// the user type their own value when picked.
+    ///
+    /// The field is a [`Choice`], holding either the picked option or the
+    /// typed text. This sets the parse functions, and parsing and validation
+    /// fail if the "other" option is picked without any text.
+    ///
+    /// Styles name the text input `{name}_other`.
+    pub fn with_other_option(self, label: impl ToString) -> Self {
+        let mut builder = self.parse_choice();
+        builder.data.other_option = Some(label.to_string());
+        builder
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::controls::other_value;
+
+    fn options() -> Vec<SelectOption> {
+        vec![
+            SelectOption::new("a").disabled(),
+            SelectOption::new("b"),
+            SelectOption::new("c"),
+        ]
+    }
+
+    #[test]
+    fn valid_values_are_kept() {
+        for reset in [InvalidReset::Clear, InvalidReset::First] {
+            assert_eq!(reset.reset("b", &options(), false), None);
+            // disabled options are still valid values
+            assert_eq!(reset.reset("a", &options(), false), None);
+            assert_eq!(reset.reset(&other_value("z"), &options(), false), None);
+            assert_eq!(reset.reset("", &options(), true), None);
+        }
+    }
+
+    #[test]
+    fn clear_empties_invalid_values() {
+        let reset = InvalidReset::Clear;
+        assert_eq!(reset.reset("z", &options(), false), Some(String::new()));
+        assert_eq!(reset.reset("z", &[], false), Some(String::new()));
+        // already empty
+        assert_eq!(reset.reset("", &options(), false), None);
+    }
+
+    #[test]
+    fn first_picks_the_first_enabled_option() {
+        let reset = InvalidReset::First;
+        assert_eq!(reset.reset("z", &options(), false), Some(String::from("b")));
+        // a blank value isn't allowed without a blank option
+        assert_eq!(reset.reset("", &options(), false), Some(String::from("b")));
+        assert_eq!(reset.reset("", &options(), true), None);
+    }
+
+    #[test]
+    fn first_clears_without_enabled_options() {
+        let reset = InvalidReset::First;
+        assert_eq!(reset.reset("z", &[], false), Some(String::new()));
+        let disabled = vec![SelectOption::new("a").disabled()];
+        assert_eq!(reset.reset("z", &disabled, false), Some(String::new()));
+        assert_eq!(reset.reset("", &[], false), None);
+    }
+}
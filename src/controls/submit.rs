@@ -2,13 +2,41 @@ use super::{
     BuilderCxFn, BuilderFn, ControlRenderData, GetterVanityControlData, VanityControlBuilder,
     VanityControlData,
 };
-use crate::{form::FormToolData, form_builder::FormBuilder, styles::FormStyle};
-use leptos::prelude::{AnyView, RwSignal, Signal};
+use crate::{
+    form::{FormStatus, FormToolData},
+    form_builder::FormBuilder,
+    styles::FormStyle,
+};
+use leptos::prelude::{AnyView, ArcRwSignal, RwSignal, Signal};
 use std::sync::Arc;
+use web_sys::SubmitEvent;
+
+/// The html name that a submit button's intent is sent with.
+pub const INTENT_NAME: &str = "intent";
 
 /// Data used for the submit button control.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-pub struct SubmitData;
+///
+/// A submit button can have an intent, to tell apart the buttons of a form
+/// with several ways to submit (ex. "Save Draft" and "Publish"). The style
+/// should render a submit button with an intent with the html name
+/// [`INTENT_NAME`] and the intent as its value. This way, the intent can be
+/// read from the submit event, and it is sent with the html form data.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct SubmitData {
+    /// The intent of submitting with this button, if there is one.
+    pub intent: Option<String>,
+    /// The submission status of the form this button submits.
+    pub(crate) status: ArcRwSignal<FormStatus>,
+}
+
+/// Gets the intent of the submit button that triggered the submit event.
+pub(crate) fn submit_intent(ev: &SubmitEvent) -> Option<String> {
+    let submitter = ev.submitter()?;
+    if submitter.get_attribute("name")? != INTENT_NAME {
+        return None;
+    }
+    submitter.get_attribute("value")
+}
 
 impl<FD: FormToolData> VanityControlData<FD> for SubmitData {
     fn render_control<FS: FormStyle>(
@@ -17,24 +45,39 @@ impl<FD: FormToolData> VanityControlData<FD> for SubmitData {
         control: ControlRenderData<FS, Self>,
         value_getter: Option<Signal<String>>,
     ) -> AnyView {
-        fs.submit(control, value_getter)
+        let status = control.data.status.read_only().into();
+        fs.submit(control, value_getter, status)
     }
 }
 impl<FD: FormToolData> GetterVanityControlData<FD> for SubmitData {}
 
 impl<FD: FormToolData> FormBuilder<FD> {
     /// Builds a submit button and adds it to the form.
-    pub fn submit(self, builder: impl BuilderFn<VanityControlBuilder<FD, SubmitData>>) -> Self {
-        self.new_vanity(builder)
+    pub fn submit(mut self, builder: impl BuilderFn<VanityControlBuilder<FD, SubmitData>>) -> Self {
+        let vanity_builder = VanityControlBuilder::new(self.submit_data());
+        let control = builder(vanity_builder);
+        self.add_vanity(control);
+        self
     }
 
     /// Builds a submit button using the form's context and adds it to the
     /// form.
     pub fn submit_cx(
-        self,
+        mut self,
         builder: impl BuilderCxFn<VanityControlBuilder<FD, SubmitData>, FD::Context>,
     ) -> Self {
-        self.new_vanity_cx(builder)
+        let vanity_builder = VanityControlBuilder::new(self.submit_data());
+        let control = builder(vanity_builder, self.cx.clone());
+        self.add_vanity(control);
+        self
+    }
+
+    /// Creates the [`SubmitData`] that is tied to this form's status.
+    fn submit_data(&self) -> SubmitData {
+        SubmitData {
+            intent: None,
+            status: self.status.clone(),
+        }
     }
 }
 
@@ -47,4 +90,15 @@ impl<FD: FormToolData> VanityControlBuilder<FD, SubmitData> {
         self.getter = Some(Arc::new(move |_| text.clone()));
         self
     }
+
+    /// Sets the intent of submitting with this button.
+    ///
+    /// The intent is given to the `on_submit` callback and the server
+    /// function of forms built with
+    /// [`get_intent_form`](crate::FormToolData::get_intent_form), and
+    /// controls can skip their validations for some intents.
+    pub fn intent(mut self, intent: impl ToString) -> Self {
+        self.data.intent = Some(intent.to_string());
+        self
+    }
 }
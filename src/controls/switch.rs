@@ -0,0 +1,94 @@
+use super::{
+    BuilderCxFn, BuilderFn, ControlBuilder, ControlData, ControlRenderData, ValidatedControlData,
+    ValidationState,
+};
+use crate::{form::FormToolData, form_builder::FormBuilder, styles::FormStyle};
+use leptos::{
+    prelude::{AnyView, RwSignal, Signal},
+    reactive::wrappers::write::SignalSetter,
+};
+
+/// Data used for the switch control.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct SwitchData {
+    pub name: String,
+    pub label: Option<String>,
+    /// The text shown when the switch is on.
+    pub on_label: Option<String>,
+    /// The text shown when the switch is off.
+    pub off_label: Option<String>,
+    /// The message to confirm with the user before the switch is toggled.
+    pub confirm: Option<String>,
+}
+
+impl<FD: FormToolData> ControlData<FD> for SwitchData {
+    type ReturnType = bool;
+
+    fn render_control<FS: FormStyle>(
+        fs: &FS,
+        _fd: RwSignal<FD>,
+        control: ControlRenderData<FS, Self>,
+        value_getter: Signal<Self::ReturnType>,
+        value_setter: SignalSetter<Self::ReturnType>,
+        validation_state: Signal<ValidationState>,
+    ) -> AnyView {
+        fs.switch(control, value_getter, value_setter, validation_state)
+    }
+}
+impl<FD: FormToolData> ValidatedControlData<FD> for SwitchData {}
+
+impl<FD: FormToolData> FormBuilder<FD> {
+    /// Builds a switch and adds it to the form.
+    ///
+    /// A switch is an on/off toggle, for settings that take effect when
+    /// they are changed.
+    pub fn switch<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderFn<ControlBuilder<FD, SwitchData, FDT>>,
+    ) -> Self {
+        self.new_control(builder)
+    }
+
+    /// Builds a switch using the form's context and adds it to the form.
+    pub fn switch_cx<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderCxFn<ControlBuilder<FD, SwitchData, FDT>, FD::Context>,
+    ) -> Self {
+        self.new_control_cx(builder)
+    }
+}
+
+impl<FD: FormToolData, FDT> ControlBuilder<FD, SwitchData, FDT> {
+    /// Sets the name of the switch.
+    ///
+    /// This is used for the html element's "name" attribute.
+    /// In forms, the name attribute is the key that the data is sent
+    /// with.
+    ///
+    /// For switch controls, the value "checked" is sent or no key value
+    /// pair is sent.
+    pub fn named(mut self, control_name: impl ToString) -> Self {
+        self.data.name = control_name.to_string();
+        self
+    }
+
+    /// Sets the label for the switch.
+    pub fn labeled(mut self, label: impl ToString) -> Self {
+        self.data.label = Some(label.to_string());
+        self
+    }
+
+    /// Sets the text shown when the switch is on and off.
+    pub fn state_labels(mut self, on_label: impl ToString, off_label: impl ToString) -> Self {
+        self.data.on_label = Some(on_label.to_string());
+        self.data.off_label = Some(off_label.to_string());
+        self
+    }
+
+    /// Asks the user to confirm with the given message before the switch
+    /// is toggled.
+    pub fn confirm(mut self, message: impl ToString) -> Self {
+        self.data.confirm = Some(message.to_string());
+        self
+    }
+}
@@ -0,0 +1,273 @@
+use super::{
+    BuilderCxFn, BuilderFn, ControlBuilder, ControlData, ControlRenderData, ValidatedControlData,
+    ValidationState,
+};
+use crate::{form::FormToolData, form_builder::FormBuilder, styles::FormStyle};
+use leptos::{
+    prelude::{AnyView, RwSignal, Signal},
+    reactive::wrappers::write::SignalSetter,
+};
+use std::{str::FromStr, sync::Arc};
+
+/// Checks a single tag before it is added.
+type TagCheckFn = dyn Fn(&str) -> Result<(), String> + Send + Sync + 'static;
+
+/// Data used for the tags input control.
+#[derive(Clone)]
+pub struct TagsInputData {
+    pub name: String,
+    pub label: Option<String>,
+    pub placeholder: Option<String>,
+    /// The characters that end a tag, along with the enter key.
+    pub separators: Vec<char>,
+    /// The most tags that can be added.
+    pub max_tags: Option<usize>,
+    /// Whether the same tag can only be added once.
+    pub unique: bool,
+    /// The checks that each tag must pass to be added.
+    tag_checks: Vec<Arc<TagCheckFn>>,
+}
+impl Default for TagsInputData {
+    fn default() -> Self {
+        TagsInputData {
+            name: String::new(),
+            label: None,
+            placeholder: None,
+            separators: vec![','],
+            max_tags: None,
+            unique: false,
+            tag_checks: Vec::new(),
+        }
+    }
+}
+impl TagsInputData {
+    /// Splits the text that the user typed into tags.
+    ///
+    /// The tags are trimmed, and empty tags are skipped.
+    pub fn split_tags<'a>(&'a self, text: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        text.split(|c| self.separators.contains(&c))
+            .map(str::trim)
+            .filter(|tag| !tag.is_empty())
+    }
+
+    /// Checks if the tag can be added to the current `tags`.
+    pub fn check_tag(&self, tag: &str, tags: &[String]) -> Result<(), String> {
+        if let Some(max) = self.max_tags {
+            if tags.len() >= max {
+                return Err(format!("No more than {} tags can be added", max));
+            }
+        }
+        if self.unique && tags.iter().any(|t| t == tag) {
+            return Err(format!("{} was already added", tag));
+        }
+        for check in self.tag_checks.iter() {
+            check(tag)?;
+        }
+        Ok(())
+    }
+}
+
+impl<FD: FormToolData> ControlData<FD> for TagsInputData {
+    type ReturnType = Vec<String>;
+
+    fn render_control<FS: FormStyle>(
+        fs: &FS,
+        _fd: RwSignal<FD>,
+        control: ControlRenderData<FS, Self>,
+        value_getter: Signal<Self::ReturnType>,
+        value_setter: SignalSetter<Self::ReturnType>,
+        validation_state: Signal<ValidationState>,
+    ) -> AnyView {
+        fs.tags_input(control, value_getter, value_setter, validation_state)
+    }
+}
+impl<FD: FormToolData> ValidatedControlData<FD> for TagsInputData {}
+
+impl<FD: FormToolData> FormBuilder<FD> {
+    /// Builds a tags input control and adds it to the form.
+    ///
+    /// A tags input lets the user type a list of values one by one.
+    pub fn tags_input<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderFn<ControlBuilder<FD, TagsInputData, FDT>>,
+    ) -> Self {
+        self.new_control(builder)
+    }
+
+    /// Builds a tags input control using the form's context and adds it to
+    /// the form.
+    pub fn tags_input_cx<FDT: Clone + PartialEq + 'static>(
+        self,
+        builder: impl BuilderCxFn<ControlBuilder<FD, TagsInputData, FDT>, FD::Context>,
+    ) -> Self {
+        self.new_control_cx(builder)
+    }
+}
+
+impl<FD: FormToolData, FDT> ControlBuilder<FD, TagsInputData, FDT> {
+    /// Sets the name of the tags input.
+    ///
+    /// This is used for the html element's "name" attribute.
+    /// In forms, the name attribute is the key that the data is sent
+    /// with.
+    pub fn named(mut self, control_name: impl ToString) -> Self {
+        self.data.name = control_name.to_string();
+        self
+    }
+
+    /// Sets the label for the tags input.
+    pub fn labeled(mut self, label: impl ToString) -> Self {
+        self.data.label = Some(label.to_string());
+        self
+    }
+
+    /// Sets the placeholder for the tags input.
+    pub fn placeholder(mut self, placeholder: impl ToString) -> Self {
+        self.data.placeholder = Some(placeholder.to_string());
+        self
+    }
+
+    /// Sets the characters that end a tag, along with the enter key.
+    ///
+    /// This defaults to a comma.
+    pub fn separators(mut self, separators: impl IntoIterator<Item = char>) -> Self {
+        self.data.separators = separators.into_iter().collect();
+        self
+    }
+
+    /// Sets the most tags that can be added.
+    ///
+    /// To also check this when validating, use
+    /// [`ValidationBuilder::max_items`](crate::ValidationBuilder::max_items).
+    pub fn max_tags(mut self, max_tags: usize) -> Self {
+        self.data.max_tags = Some(max_tags);
+        self
+    }
+
+    /// Only allows each tag to be added once.
+    ///
+    /// To also check this when validating, use
+    /// [`ValidationBuilder::unique_items`](crate::ValidationBuilder::unique_items).
+    pub fn unique(mut self) -> Self {
+        self.data.unique = true;
+        self
+    }
+
+    /// Adds a check that each tag must pass to be added.
+    pub fn validate_tag(
+        mut self,
+        check: impl Fn(&str) -> Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        self.data.tag_checks.push(Arc::new(check));
+        self
+    }
+}
+
+impl<FD: FormToolData, FDT> ControlBuilder<FD, TagsInputData, FDT> {
+    /// Sets the parse functions to use the [`FromStr`] and [`ToString`]
+    /// traits on each tag, like
+    /// [`parse_strings`](ControlBuilder::parse_strings).
+    ///
+    /// Tags that can't be parsed are not added.
+    pub fn parse_tags<T>(self) -> Self
+    where
+        FDT: FromIterator<T> + IntoIterator<Item = T>,
+        T: FromStr + ToString,
+        <T as FromStr>::Err: ToString,
+    {
+        self.validate_tag(|tag| tag.parse::<T>().map(|_| ()).map_err(|e| e.to_string()))
+            .parse_strings()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::styles::GridFormStyle;
+
+    #[derive(Clone)]
+    struct Post;
+    impl FormToolData for Post {
+        type Style = GridFormStyle;
+        type Context = ();
+
+        fn build_form(fb: FormBuilder<Self>) -> FormBuilder<Self> {
+            fb
+        }
+    }
+
+    fn tags_input() -> ControlBuilder<Post, TagsInputData, Vec<u32>> {
+        ControlBuilder::new(TagsInputData::default())
+    }
+
+    fn tags(tags: &[&str]) -> Vec<String> {
+        tags.iter().map(|tag| tag.to_string()).collect()
+    }
+
+    #[test]
+    fn tags_are_split_and_trimmed() {
+        let data = TagsInputData::default();
+        let split: Vec<_> = data.split_tags(" rust, leptos ,,  ,forms").collect();
+        assert_eq!(split, ["rust", "leptos", "forms"]);
+        assert_eq!(data.split_tags("  ").count(), 0);
+    }
+
+    #[test]
+    fn tags_are_split_on_every_separator() {
+        let data = tags_input().separators([',', ';', ' ']).data;
+        let split: Vec<_> = data.split_tags("a;b c,d").collect();
+        assert_eq!(split, ["a", "b", "c", "d"]);
+        // the default comma is replaced
+        let data = tags_input().separators([';']).data;
+        let split: Vec<_> = data.split_tags("a,b;c").collect();
+        assert_eq!(split, ["a,b", "c"]);
+    }
+
+    #[test]
+    fn max_tags_stops_adding() {
+        let data = tags_input().max_tags(2).data;
+        assert_eq!(data.check_tag("a", &tags(&[])), Ok(()));
+        assert_eq!(data.check_tag("b", &tags(&["a"])), Ok(()));
+        assert_eq!(
+            data.check_tag("c", &tags(&["a", "b"])),
+            Err(String::from("No more than 2 tags can be added"))
+        );
+    }
+
+    #[test]
+    fn unique_rejects_added_tags() {
+        let data = tags_input().data;
+        assert_eq!(data.check_tag("a", &tags(&["a"])), Ok(()));
+
+        let data = tags_input().unique().data;
+        assert_eq!(data.check_tag("b", &tags(&["a"])), Ok(()));
+        assert_eq!(
+            data.check_tag("a", &tags(&["a"])),
+            Err(String::from("a was already added"))
+        );
+    }
+
+    #[test]
+    fn every_tag_check_must_pass() {
+        let data = tags_input()
+            .validate_tag(|tag| match tag.len() <= 3 {
+                true => Ok(()),
+                false => Err(String::from("Too long")),
+            })
+            .validate_tag(|tag| match tag.starts_with('x') {
+                true => Err(String::from("No x")),
+                false => Ok(()),
+            })
+            .data;
+        assert_eq!(data.check_tag("abc", &[]), Ok(()));
+        assert_eq!(data.check_tag("abcd", &[]), Err(String::from("Too long")));
+        assert_eq!(data.check_tag("xy", &[]), Err(String::from("No x")));
+    }
+
+    #[test]
+    fn parse_tags_checks_each_tag() {
+        let data = tags_input().parse_tags::<u32>().data;
+        assert_eq!(data.check_tag("12", &[]), Ok(()));
+        assert!(data.check_tag("twelve", &[]).is_err());
+    }
+}
@@ -4,7 +4,7 @@ use super::{
 };
 use crate::{form::FormToolData, form_builder::FormBuilder, styles::FormStyle};
 use leptos::{
-    prelude::{AnyView, RwSignal, Signal},
+    prelude::{AnyView, RwSignal, Set, Signal},
     reactive::wrappers::write::SignalSetter,
 };
 
@@ -16,6 +16,12 @@ pub struct TextInputData {
     pub placeholder: Option<String>,
     pub input_type: &'static str,
     pub update_event: UpdateEvent,
+    /// The mask that formats the text as the user types it.
+    ///
+    /// The control strips the mask from what the style sets, so the style
+    /// only needs to format the text it shows. See [`ControlBuilder::mask`]
+    /// for the format.
+    pub mask: Option<String>,
 }
 
 impl Default for TextInputData {
@@ -26,8 +32,97 @@ impl Default for TextInputData {
             label: None,
             input_type: "input",
             update_event: UpdateEvent::default(),
+            mask: None,
+        }
+    }
+}
+
+/// Returns true if the mask character is a slot that `c` can fill.
+///
+/// Mask characters that aren't slots are literals.
+fn fills_slot(mask_char: char, c: char) -> bool {
+    match mask_char {
+        '9' => c.is_ascii_digit(),
+        'a' => c.is_alphabetic(),
+        '*' => c.is_alphanumeric(),
+        _ => false,
+    }
+}
+
+/// Returns true if the mask character is a slot.
+fn is_slot(mask_char: char) -> bool {
+    matches!(mask_char, '9' | 'a' | '*')
+}
+
+/// Removes a mask's formatting from `text`.
+///
+/// This keeps only the characters that fill the mask's slots, dropping the
+/// literals and any characters that don't fit.
+pub fn strip_mask(mask: &str, text: &str) -> String {
+    let mut raw = String::new();
+    let mut mask_chars = mask.chars().peekable();
+    for c in text.chars() {
+        loop {
+            let Some(&mask_char) = mask_chars.peek() else {
+                return raw;
+            };
+            if !is_slot(mask_char) {
+                // skip the literal, consuming the character if it matches
+                mask_chars.next();
+                if mask_char == c {
+                    break;
+                }
+                continue;
+            }
+            if fills_slot(mask_char, c) {
+                raw.push(c);
+                mask_chars.next();
+            }
+            break;
         }
     }
+    raw
+}
+
+/// Formats the `raw` characters with the mask.
+///
+/// Literals are only added up to the last filled slot.
+pub fn apply_mask(mask: &str, raw: &str) -> String {
+    let mut formatted = String::new();
+    let mut raw_chars = raw.chars().peekable();
+    for mask_char in mask.chars() {
+        if raw_chars.peek().is_none() {
+            break;
+        }
+        if !is_slot(mask_char) {
+            formatted.push(mask_char);
+            continue;
+        }
+        // skip raw characters that can't fill this slot
+        match raw_chars.find(|c| fills_slot(mask_char, *c)) {
+            Some(c) => formatted.push(c),
+            None => break,
+        }
+    }
+    formatted
+}
+
+/// Gets the caret position, in characters, in the masked text that comes
+/// after `raw_before` filled slots.
+pub fn masked_caret(mask: &str, raw_before: usize) -> usize {
+    if raw_before == 0 {
+        return 0;
+    }
+    let mut filled = 0;
+    for (i, mask_char) in mask.chars().enumerate() {
+        if is_slot(mask_char) {
+            filled += 1;
+            if filled == raw_before {
+                return i + 1;
+            }
+        }
+    }
+    mask.chars().count()
 }
 
 impl<FD: FormToolData> ControlData<FD> for TextInputData {
@@ -41,6 +136,14 @@ impl<FD: FormToolData> ControlData<FD> for TextInputData {
         value_setter: SignalSetter<Self::ReturnType>,
         validation_state: Signal<ValidationState>,
     ) -> AnyView {
+        // the parse function is given the text without the mask's
+        // formatting, whatever the style sets
+        let value_setter = match control.data.mask.clone() {
+            Some(mask) => {
+                SignalSetter::map(move |value: String| value_setter.set(strip_mask(&mask, &value)))
+            }
+            None => value_setter,
+        };
         fs.text_input(control, value_getter, value_setter, validation_state)
     }
 }
@@ -89,12 +192,18 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, TextInputData, FDT> {
     }
 
     /// Sets the text input to be the "password" type.
+    ///
+    /// For a password that can be revealed or show its strength, see the
+    /// [`password`](FormBuilder::password) control.
     pub fn password(mut self) -> Self {
         self.data.input_type = "password";
         self
     }
 
     /// Sets the text input to be the "date" type.
+    ///
+    /// For parsing into date types, use a
+    /// [`date_input`](FormBuilder::date_input) instead.
     pub fn date(mut self) -> Self {
         self.data.input_type = "date";
         self
@@ -106,9 +215,93 @@ impl<FD: FormToolData, FDT> ControlBuilder<FD, TextInputData, FDT> {
         self
     }
 
+    /// Sets a mask that formats the text as the user types it, like
+    /// `"(999) 999-9999"` for a phone number.
+    ///
+    /// In the mask, `9` is a digit, `a` is a letter and `*` is a letter or
+    /// digit. Any other character is a literal that is added for the user.
+    ///
+    /// The parse function is given the text with the mask's formatting
+    /// stripped, and the mask is applied again to what the unparse
+    /// function returns.
+    pub fn mask(mut self, mask: impl ToString) -> Self {
+        self.data.mask = Some(mask.to_string());
+        self
+    }
+
     /// Sets the event that is used to update the form data.
     pub fn update_on(mut self, event: UpdateEvent) -> Self {
         self.data.update_event = event;
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PHONE: &str = "(999) 999-9999";
+
+    #[test]
+    fn strip_mask_keeps_slot_characters() {
+        assert_eq!(strip_mask(PHONE, "(555) 123-4567"), "5551234567");
+        assert_eq!(strip_mask(PHONE, "5551234567"), "5551234567");
+        assert_eq!(strip_mask(PHONE, "(555) 12"), "55512");
+        assert_eq!(strip_mask(PHONE, ""), "");
+    }
+
+    #[test]
+    fn strip_mask_drops_characters_that_dont_fit() {
+        assert_eq!(strip_mask(PHONE, "(5x55) 1"), "5551");
+        assert_eq!(strip_mask("aa-99", "a1-23"), "a");
+        assert_eq!(strip_mask("aa-99", "ab-23"), "ab23");
+        // characters past the end of the mask are dropped
+        assert_eq!(strip_mask("99", "1234"), "12");
+    }
+
+    #[test]
+    fn apply_mask_adds_literals_up_to_the_last_slot() {
+        assert_eq!(apply_mask(PHONE, "5551234567"), "(555) 123-4567");
+        assert_eq!(apply_mask(PHONE, "555"), "(555");
+        assert_eq!(apply_mask(PHONE, "5551"), "(555) 1");
+        assert_eq!(apply_mask(PHONE, ""), "");
+        assert_eq!(apply_mask("**-**", "ab12"), "ab-12");
+    }
+
+    #[test]
+    fn apply_mask_skips_characters_that_dont_fit() {
+        assert_eq!(apply_mask(PHONE, "5a5"), "(55");
+        assert_eq!(apply_mask("aa-99", "ab23"), "ab-23");
+        assert_eq!(apply_mask("99", "1234"), "12");
+    }
+
+    #[test]
+    fn strip_mask_keeps_stripped_text() {
+        // the control strips what the style sets, which may already be
+        // stripped
+        assert_eq!(strip_mask(PHONE, "5551234567"), "5551234567");
+        assert_eq!(strip_mask(PHONE, "555"), "555");
+        assert_eq!(strip_mask("aa-99", "ab23"), "ab23");
+    }
+
+    #[test]
+    fn masks_round_trip() {
+        for raw in ["", "5", "555", "5551", "5551234567"] {
+            assert_eq!(strip_mask(PHONE, &apply_mask(PHONE, raw)), raw);
+        }
+    }
+
+    #[test]
+    fn masked_caret_goes_after_the_filled_slots() {
+        assert_eq!(masked_caret(PHONE, 0), 0);
+        // "(5|"
+        assert_eq!(masked_caret(PHONE, 1), 2);
+        // "(555|" stays before the literals
+        assert_eq!(masked_caret(PHONE, 3), 4);
+        // "(555) 1|"
+        assert_eq!(masked_caret(PHONE, 4), 7);
+        assert_eq!(masked_caret(PHONE, 10), 14);
+        // past the last slot, the caret is at the end
+        assert_eq!(masked_caret(PHONE, 11), 14);
+    }
+}
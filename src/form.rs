@@ -1,26 +1,36 @@
-use crate::{controls::ValidationFn, form_builder::FormBuilder, styles::FormStyle};
+use crate::{
+    controls::{IntentFilter, ValidationFn},
+    form_builder::FormBuilder,
+    styles::FormStyle,
+};
 use ev::SubmitEvent;
 use leptos::{
-    prelude::{AnyView, GetUntracked, IntoAny, RwSignal},
+    prelude::{
+        Action, AnyView, ArcRwSignal, Effect, Get, GetUntracked, IntoAny, RwSignal, Set, Signal,
+        With, WithUntracked,
+    },
     server::ServerAction,
     server_fn::{
         client::Client,
-        codec::{Json, PostUrl},
+        codec::{Encoding, PostUrl},
         request::ClientReq,
         Http, ServerFn,
     },
     *,
 };
 use serde::de::DeserializeOwned;
-use std::sync::Arc;
+use std::{fmt::Display, sync::Arc};
 use web_sys::FormData;
 
+/// A validation function, along with the submit intents that it runs for.
+pub(crate) type IntentValidation<FD> = (Arc<dyn ValidationFn<FD>>, IntentFilter);
+
 /// A type that can be used to validate the form data.
 ///
 /// This can be useful to use the same validation logic on the front
 /// end and backend without duplicating the logic.
 pub struct FormValidator<FD> {
-    pub(crate) validations: Vec<Arc<dyn ValidationFn<FD>>>,
+    pub(crate) validations: Vec<IntentValidation<FD>>,
 }
 
 impl<FD: FormToolData> FormValidator<FD> {
@@ -29,26 +39,181 @@ impl<FD: FormToolData> FormValidator<FD> {
     /// This runs all the validation functions for all the fields
     /// in the form. The first falure to occur (if any) will be returned.
     pub fn validate(&self, form_data: &FD) -> Result<(), String> {
-        for v in self.validations.iter() {
-            (*v)(form_data)?;
+        self.validate_intent(form_data, None)
+    }
+
+    /// Validates the given form data, as it would be validated when
+    /// submitted with the given intent.
+    ///
+    /// This skips the validations that do not run for `intent`. With an
+    /// intent of `None`, all the validations run.
+    pub fn validate_intent(&self, form_data: &FD, intent: Option<&str>) -> Result<(), String> {
+        for (v, intents) in self.validations.iter() {
+            if intents.runs_for(intent) {
+                (*v)(form_data)?;
+            }
         }
         Ok(())
     }
 }
 
+/// The submission status of a [`Form`].
+///
+/// `O` is the output of the server function that the form is sent to.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum FormStatus<O = ()> {
+    /// The form has not been submitted yet.
+    #[default]
+    Idle,
+    /// The form is being validated, or the
+    /// [`before_submit`](FormBuilder::before_submit) hooks are running.
+    Validating,
+    /// The form data has been sent and the server has not responded yet.
+    Submitting,
+    /// The server responded with a success, and this output.
+    Succeeded(O),
+    /// The server responded with an error, or a
+    /// [`before_submit`](FormBuilder::before_submit) hook failed.
+    Failed(String),
+}
+impl<O> FormStatus<O> {
+    /// Returns true if self is `Idle`.
+    pub fn is_idle(&self) -> bool {
+        matches!(self, FormStatus::Idle)
+    }
+
+    /// Returns true if self is either `Validating` or `Submitting`.
+    ///
+    /// While pending, the form will not be submitted again.
+    pub fn is_pending(&self) -> bool {
+        matches!(self, FormStatus::Validating | FormStatus::Submitting)
+    }
+
+    /// Returns true if self is `Succeeded`.
+    ///
+    /// The server's output can be read with [`output`](Self::output).
+    pub fn is_succeeded(&self) -> bool {
+        matches!(self, FormStatus::Succeeded(_))
+    }
+
+    /// Returns true if self is `Failed`.
+    pub fn is_failed(&self) -> bool {
+        matches!(self, FormStatus::Failed(_))
+    }
+
+    /// Gets the server's output if the submission succeeded.
+    pub fn output(&self) -> Option<&O> {
+        match self {
+            FormStatus::Succeeded(output) => Some(output),
+            _ => None,
+        }
+    }
+
+    /// Gets the error message if the submission failed.
+    pub fn err_msg(&self) -> Option<&String> {
+        match self {
+            FormStatus::Failed(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+impl FormStatus {
+    /// Gives a `Succeeded` status the `output`.
+    ///
+    /// If there is no output, a `Succeeded` status becomes `Idle`.
+    pub(crate) fn with_output<O>(self, output: Option<O>) -> FormStatus<O> {
+        match self {
+            FormStatus::Idle => FormStatus::Idle,
+            FormStatus::Validating => FormStatus::Validating,
+            FormStatus::Submitting => FormStatus::Submitting,
+            FormStatus::Succeeded(()) => match output {
+                Some(output) => FormStatus::Succeeded(output),
+                None => FormStatus::Idle,
+            },
+            FormStatus::Failed(e) => FormStatus::Failed(e),
+        }
+    }
+}
+
+/// Derives the status with the output from the form's status and the
+/// server's last output.
+fn status_with_output<O: Clone + Send + Sync + 'static>(
+    status: &ArcRwSignal<FormStatus>,
+    output: &ArcRwSignal<Option<O>>,
+) -> Signal<FormStatus<O>> {
+    let status = status.clone();
+    let output = output.clone();
+    Signal::derive(move || status.get().with_output(output.get()))
+}
+
+/// A handle to a [`Form`] that is given to the form's callbacks.
+///
+/// This allows the callbacks to change the form after it has been
+/// submitted.
+pub struct FormHandle<FD: FormToolData, O = ()> {
+    /// The form data signal.
+    pub fd: RwSignal<FD>,
+    pub(crate) initial: Arc<FD>,
+    pub(crate) status: ArcRwSignal<FormStatus>,
+    pub(crate) output: ArcRwSignal<Option<O>>,
+}
+impl<FD: FormToolData, O> Clone for FormHandle<FD, O> {
+    fn clone(&self) -> Self {
+        FormHandle {
+            fd: self.fd,
+            initial: self.initial.clone(),
+            status: self.status.clone(),
+            output: self.output.clone(),
+        }
+    }
+}
+
+impl<FD: FormToolData, O: Clone + Send + Sync + 'static> FormHandle<FD, O> {
+    /// Resets the form data to the data that the form was built with.
+    pub fn reset(&self) {
+        self.fd.set((*self.initial).clone());
+    }
+
+    /// Gets the submission status of the form.
+    pub fn status(&self) -> Signal<FormStatus<O>> {
+        status_with_output(&self.status, &self.output)
+    }
+
+    /// Shows the given message as a form level error.
+    ///
+    /// This sets the form's status to [`FormStatus::Failed`].
+    pub fn set_error(&self, msg: impl ToString) {
+        self.status.set(FormStatus::Failed(msg.to_string()));
+    }
+}
+
+/// Calls the given callback with each of the server's responses.
+pub(crate) type WatchResponses<O, E> = Arc<dyn Fn(Box<dyn Fn(&Result<O, E>)>)>;
+
 /// A constructed, rendered form object.
 ///
 /// With this, you can render the form, get the form data, or get
 /// a validator for the data.
-pub struct Form<FD: FormToolData> {
+///
+/// `O` and `E` are the output and error of the server function that the
+/// form is sent to.
+pub struct Form<FD: FormToolData, O = (), E = ()> {
     /// The form data signal.
     pub fd: RwSignal<FD>,
     /// The list of validations
-    pub(crate) validations: Vec<Arc<dyn ValidationFn<FD>>>,
+    pub(crate) validations: Vec<IntentValidation<FD>>,
+    pub(crate) status: ArcRwSignal<FormStatus>,
+    /// The output of the server's last successful response.
+    pub(crate) output: ArcRwSignal<Option<O>>,
+    /// Watches the responses of the server action the form is sent to, if
+    /// there is one.
+    pub(crate) watch_responses: Option<WatchResponses<O, E>>,
+    /// The data the form was built with, for resetting the form.
+    pub(crate) initial: Arc<FD>,
     pub(crate) view: AnyView,
 }
 
-impl<FD: FormToolData> Form<FD> {
+impl<FD: FormToolData, O: Clone + Send + Sync + 'static, E: 'static> Form<FD, O, E> {
     /// Gets the [`FormValidator`] for this form.
     pub fn validator(&self) -> FormValidator<FD> {
         FormValidator {
@@ -56,6 +221,107 @@ impl<FD: FormToolData> Form<FD> {
         }
     }
 
+    /// Gets the submission status of this form.
+    ///
+    /// Forms that are not submitted through a server action stay `Idle`.
+    pub fn status(&self) -> Signal<FormStatus<O>> {
+        status_with_output(&self.status, &self.output)
+    }
+
+    /// Gets a [`FormHandle`] for this form.
+    pub fn handle(&self) -> FormHandle<FD, O> {
+        FormHandle {
+            fd: self.fd,
+            initial: self.initial.clone(),
+            status: self.status.clone(),
+            output: self.output.clone(),
+        }
+    }
+
+    /// Resets the form data to the data that the form was built with.
+    pub fn reset(&self) {
+        self.handle().reset();
+    }
+
+    /// Calls `on_success` each time the server responds to the form with
+    /// a success.
+    ///
+    /// This is never called for forms that are not sent to a server action.
+    pub fn on_success(self, on_success: impl Fn(&O, FormHandle<FD, O>) + 'static) -> Self {
+        let handle = self.handle();
+        self.on_response(move |response| {
+            if let Ok(output) = response {
+                on_success(output, handle.clone());
+            }
+        });
+        self
+    }
+
+    /// Calls `on_error` each time the server responds to the form with an
+    /// error.
+    ///
+    /// The error is already shown as a form level error through the form's
+    /// [`status`](Self::status), so this is for any extra handling.
+    ///
+    /// This is never called for forms that are not sent to a server action.
+    pub fn on_error(self, on_error: impl Fn(&E, FormHandle<FD, O>) + 'static) -> Self {
+        let handle = self.handle();
+        self.on_response(move |response| {
+            if let Err(e) = response {
+                on_error(e, handle.clone());
+            }
+        });
+        self
+    }
+
+    /// Calls `f` with each of the server's responses, if the form is sent to
+    /// a server action.
+    fn on_response(&self, f: impl Fn(&Result<O, E>) + 'static) {
+        if let Some(ref watch_responses) = self.watch_responses {
+            watch_responses(Box::new(f));
+        }
+    }
+
+    /// Resets the form data each time the form is submitted successfully.
+    pub fn reset_on_success(self) -> Self {
+        self.when_succeeded(|_, handle| handle.reset());
+        self
+    }
+
+    /// Navigates to the given url each time the form is submitted
+    /// successfully.
+    ///
+    /// This uses [`use_navigate`](leptos_router::hooks::use_navigate), so the
+    /// form must be built inside a
+    /// [`Router`](leptos_router::components::Router).
+    pub fn navigate_on_success(self, url: impl ToString) -> Self {
+        let url = url.to_string();
+        let navigate = leptos_router::hooks::use_navigate();
+        self.when_succeeded(move |_, _| navigate(&url, Default::default()));
+        self
+    }
+
+    /// Calls `f` with the server's output each time the form's status
+    /// changes to `Succeeded`.
+    fn when_succeeded(&self, f: impl Fn(&O, FormHandle<FD, O>) + 'static) {
+        let handle = self.handle();
+        let status = self.status.clone();
+        let output = self.output.clone();
+        Effect::watch(
+            move || status.with(FormStatus::is_succeeded),
+            move |succeeded, prev, _| {
+                if *succeeded && prev != Some(&true) {
+                    output.with_untracked(|output| {
+                        if let Some(output) = output {
+                            f(output, handle.clone());
+                        }
+                    });
+                }
+            },
+            false,
+        );
+    }
+
     /// Validates the [`FormToolData`], returning the result.
     pub fn validate(&self) -> Result<(), String> {
         let validator = self.validator();
@@ -74,12 +340,89 @@ impl<FD: FormToolData> Form<FD> {
     }
 }
 
-impl<FD: FormToolData> IntoAny for Form<FD> {
+impl<FD: FormToolData, O, E> IntoAny for Form<FD, O, E> {
     fn into_any(self) -> AnyView {
         self.view
     }
 }
 
+/// The protocol of a server function that a [`Form`] can send its data
+/// to.
+///
+/// This tells how a plain html form can send the server function's input,
+/// for when JavaScript is not available.
+pub trait FormProtocol {
+    /// Gets the html form `method` and `enctype` that send the server
+    /// function's input, or `None` if an html form can't send it.
+    fn html_form() -> Option<(&'static str, &'static str)>;
+}
+impl<InputProtocol: Encoding, OutputProtocol> FormProtocol for Http<InputProtocol, OutputProtocol> {
+    fn html_form() -> Option<(&'static str, &'static str)> {
+        let method = match InputProtocol::METHOD.as_str() {
+            "GET" => "get",
+            "POST" => "post",
+            _ => return None,
+        };
+        match InputProtocol::CONTENT_TYPE {
+            enctype @ ("application/x-www-form-urlencoded" | "multipart/form-data") => {
+                Some((method, enctype))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A server function that a [`Form`] can send its data to.
+///
+/// This is implemented for all server functions over http whose output and
+/// error can be held by the form, whatever their encoding is.
+pub trait FormServerFn:
+    ServerFn<
+        Protocol: FormProtocol,
+        Output: Clone + Send + Sync + 'static,
+        Error: Display + Send + Sync + 'static,
+    > + Clone
+    + Send
+    + Sync
+    + 'static
+{
+}
+impl<S> FormServerFn for S where
+    S: ServerFn<
+            Protocol: FormProtocol,
+            Output: Clone + Send + Sync + 'static,
+            Error: Display + Send + Sync + 'static,
+        > + Clone
+        + Send
+        + Sync
+        + 'static
+{
+}
+
+/// A [`FormServerFn`] that can be used with an
+/// [`ActionForm`](leptos::form::ActionForm).
+///
+/// The html form's data is sent url encoded, so the server function must
+/// take [`PostUrl`] input. The output can use any encoding.
+pub trait ActionFormServerFn<OutputProtocol>:
+    FormServerFn
+    + DeserializeOwned
+    + ServerFn<
+        Protocol = Http<PostUrl, OutputProtocol>,
+        Client: Client<Self::Error, Request: ClientReq<Self::Error, FormData: From<FormData>>>,
+    >
+{
+}
+impl<S, OutputProtocol> ActionFormServerFn<OutputProtocol> for S where
+    S: FormServerFn
+        + DeserializeOwned
+        + ServerFn<
+            Protocol = Http<PostUrl, OutputProtocol>,
+            Client: Client<Self::Error, Request: ClientReq<Self::Error, FormData: From<FormData>>>,
+        >
+{
+}
+
 /// A trait allowing a form to be built around its containing data.
 ///
 /// This trait defines a function that can be used to build all the data
@@ -107,19 +450,26 @@ pub trait FormToolData: Clone + Send + Sync + 'static {
 
     /// Constructs a [`Form`] for this [`FormToolData`] type.
     ///
-    /// This renders the form as a enhanced
-    /// [`ActionForm`](leptos::form::ActionForm) that sends the form data
+    /// This renders the form as a html form that sends the form data
     /// directly by calling the server function.
     ///
     /// By doing this, we avoid doing the
     /// [`FromFormData`](leptos::form::FromFormData)
-    /// conversion. However, to support
+    /// conversion, and the server function can use any encoding. However, to
+    /// support
     /// [Progressive Enhancement](https://book.leptos.dev/progressive_enhancement/index.html),
     /// you should name the form elements to work with a plain ActionForm
     /// anyway. If progresssive enhancement is not important to you, you may
     /// freely use this version.
     ///
+    /// Without JavaScript, the html form is sent to the server function
+    /// directly, which only works for the encodings that an html form can
+    /// send: `GetUrl`, `PostUrl` and `MultipartFormData`. For other
+    /// encodings, the html form is not given an action. See
+    /// [`FormProtocol`].
+    ///
     /// For the other ways to construct a [`Form`], see:
+    /// - [`get_intent_form`](Self::get_intent_form)
     /// - [`get_action_form`](Self::get_action_form)
     /// - [`get_plain_form`](Self::get_plain_form)
     /// - [`get_form_controls`](Self::get_form_controls)
@@ -129,24 +479,54 @@ pub trait FormToolData: Clone + Send + Sync + 'static {
         on_submit: F,
         style: Self::Style,
         context: Self::Context,
-    ) -> Form<Self>
+    ) -> Form<Self, ServFn::Output, ServFn::Error>
     where
-        ServFn: DeserializeOwned
-            + ServerFn<Protocol = Http<PostUrl, Json>>
-            + From<Self>
-            + Clone
-            + Send
-            + Sync
-            + 'static,
-        <<ServFn::Client as Client<ServFn::Error>>::Request as ClientReq<ServFn::Error>>::FormData:
-            From<FormData>,
-        ServFn::Output: Send + Sync + 'static,
-        ServFn::Error: Send + Sync + 'static,
-        <ServFn as ServerFn>::Client: Client<<ServFn as ServerFn>::Error>,
+        ServFn: FormServerFn + From<Self>,
     {
         let builder = FormBuilder::new(context);
         let builder = Self::build_form(builder);
-        builder.build_form(action, on_submit, self, style)
+        builder.build_form(
+            action,
+            move |ev, fd, _intent| on_submit(ev, fd),
+            |fd, _intent| ServFn::from(fd),
+            self,
+            style,
+        )
+    }
+
+    /// Constructs a [`Form`] for this [`FormToolData`] type.
+    ///
+    /// This is the same as [`get_form`](Self::get_form), but the intent of
+    /// the submit button that was used is given to `on_submit` and to the
+    /// server function, which is created with
+    /// `From<(Self, Option<String>)>`.
+    /// See [`SubmitData`](crate::controls::submit::SubmitData) for more on
+    /// intents.
+    ///
+    /// For the other ways to construct a [`Form`], see:
+    /// - [`get_form`](Self::get_form)
+    /// - [`get_action_form`](Self::get_action_form)
+    /// - [`get_plain_form`](Self::get_plain_form)
+    /// - [`get_form_controls`](Self::get_form_controls)
+    fn get_intent_form<ServFn, F: Fn(SubmitEvent, RwSignal<Self>, Option<String>) + 'static>(
+        self,
+        action: ServerAction<ServFn>,
+        on_submit: F,
+        style: Self::Style,
+        context: Self::Context,
+    ) -> Form<Self, ServFn::Output, ServFn::Error>
+    where
+        ServFn: FormServerFn + From<(Self, Option<String>)>,
+    {
+        let builder = FormBuilder::new(context);
+        let builder = Self::build_form(builder);
+        builder.build_form(
+            action,
+            on_submit,
+            |fd, intent| ServFn::from((fd, intent)),
+            self,
+            style,
+        )
     }
 
     /// Constructs a [`Form`] for this [`FormToolData`] type.
@@ -155,34 +535,111 @@ pub trait FormToolData: Clone + Send + Sync + 'static {
     /// [`ActionForm`](leptos::form::ActionForm)
     /// component.
     ///
+    /// The html form data is sent url encoded, so the server function must
+    /// use the [`PostUrl`] input encoding. See [`ActionFormServerFn`].
+    ///
+    /// The intent of the submit button that was used, if any, is sent with
+    /// the html form data under the name
+    /// [`INTENT_NAME`](crate::controls::submit::INTENT_NAME).
+    ///
     /// For the other ways to construct a [`Form`], see:
     /// - [`get_form`](Self::get_form)
+    /// - [`get_intent_action_form`](Self::get_intent_action_form)
     /// - [`get_plain_form`](Self::get_plain_form)
     /// - [`get_form_controls`](Self::get_form_controls)
-    fn get_action_form<ServFn, F: Fn(SubmitEvent, RwSignal<Self>) + 'static>(
+    fn get_action_form<ServFn, OutputProtocol, F: Fn(SubmitEvent, RwSignal<Self>) + 'static>(
         self,
         action: ServerAction<ServFn>,
         on_submit: F,
         style: Self::Style,
         context: Self::Context,
-    ) -> Form<Self>
+    ) -> Form<Self, ServFn::Output, ServFn::Error>
     where
-        ServFn: DeserializeOwned
-            + ServerFn<Protocol = Http<PostUrl, Json>>
-            + From<Self>
-            + Clone
-            + Send
-            + Sync
-            + 'static,
-        <<ServFn::Client as Client<ServFn::Error>>::Request as ClientReq<ServFn::Error>>::FormData:
-            From<FormData>,
-        ServFn::Output: Send + Sync + 'static,
-        ServFn::Error: Send + Sync + 'static,
-        <ServFn as ServerFn>::Client: Client<<ServFn as ServerFn>::Error>,
+        ServFn: ActionFormServerFn<OutputProtocol> + From<Self>,
     {
         let builder = FormBuilder::new(context);
         let builder = Self::build_form(builder);
-        builder.build_action_form(action, on_submit, self, style)
+        builder.build_action_form(
+            action,
+            move |ev, fd, _intent| on_submit(ev, fd),
+            |fd, _intent| ServFn::from(fd),
+            self,
+            style,
+        )
+    }
+
+    /// Constructs a [`Form`] for this [`FormToolData`] type.
+    ///
+    /// This is the same as [`get_action_form`](Self::get_action_form), but
+    /// the intent of the submit button that was used is given to
+    /// `on_submit`. When the
+    /// [`before_submit`](FormBuilder::before_submit) hooks run, the action
+    /// is dispatched with the server function created with
+    /// `From<(Self, Option<String>)>`, so it gets the intent too.
+    ///
+    /// For the other ways to construct a [`Form`], see:
+    /// - [`get_form`](Self::get_form)
+    /// - [`get_action_form`](Self::get_action_form)
+    /// - [`get_plain_form`](Self::get_plain_form)
+    /// - [`get_form_controls`](Self::get_form_controls)
+    fn get_intent_action_form<ServFn, OutputProtocol, F>(
+        self,
+        action: ServerAction<ServFn>,
+        on_submit: F,
+        style: Self::Style,
+        context: Self::Context,
+    ) -> Form<Self, ServFn::Output, ServFn::Error>
+    where
+        ServFn: ActionFormServerFn<OutputProtocol> + From<(Self, Option<String>)>,
+        F: Fn(SubmitEvent, RwSignal<Self>, Option<String>) + 'static,
+    {
+        let builder = FormBuilder::new(context);
+        let builder = Self::build_form(builder);
+        builder.build_action_form(
+            action,
+            on_submit,
+            |fd, intent| ServFn::from((fd, intent)),
+            self,
+            style,
+        )
+    }
+
+    /// Constructs a [`Form`] for this [`FormToolData`] type.
+    ///
+    /// This sends the html form's data as multipart [`FormData`], which
+    /// allows files from [`file_input`](FormBuilder::file_input) controls
+    /// to be uploaded.
+    ///
+    /// Multipart server functions can't be used with a
+    /// [`ServerAction`], so `action` should be a local action that calls
+    /// the server function:
+    /// ```ignore
+    /// let action = Action::new_local(|data: &FormData| upload(data.clone().into()));
+    /// ```
+    /// The server function gets each control's value under the control's
+    /// name, and the intent of the submit button that was used, if any,
+    /// under [`INTENT_NAME`](crate::controls::submit::INTENT_NAME).
+    ///
+    /// For the other ways to construct a [`Form`], see:
+    /// - [`get_form`](Self::get_form)
+    /// - [`get_action_form`](Self::get_action_form)
+    /// - [`get_plain_form`](Self::get_plain_form)
+    /// - [`get_form_controls`](Self::get_form_controls)
+    fn get_multipart_form<O, E, F>(
+        self,
+        action: Action<FormData, Result<O, E>>,
+        on_submit: F,
+        style: Self::Style,
+        context: Self::Context,
+    ) -> Form<Self, O, E>
+    where
+        O: Clone + Send + Sync + 'static,
+        E: Display + 'static,
+        F: Fn(SubmitEvent, RwSignal<Self>) + 'static,
+    {
+        let builder = FormBuilder::new(context);
+        let builder = Self::build_form(builder);
+        builder.build_multipart_form(action, on_submit, self, style)
     }
 
     /// Constructs a [`Form`] for this [`FormToolData`] type.
@@ -247,3 +704,95 @@ pub trait FormToolData: Clone + Send + Sync + 'static {
         validator.validate(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use leptos::server_fn::codec::{GetUrl, Json, PutJson, PutUrl};
+
+    #[test]
+    fn html_form_follows_the_input_encoding() {
+        assert_eq!(
+            <Http<PostUrl, Json>>::html_form(),
+            Some(("post", "application/x-www-form-urlencoded"))
+        );
+        assert_eq!(
+            <Http<GetUrl, Json>>::html_form(),
+            Some(("get", "application/x-www-form-urlencoded"))
+        );
+        assert_eq!(<Http<PutUrl, Json>>::html_form(), None);
+        assert_eq!(<Http<Json, Json>>::html_form(), None);
+        assert_eq!(<Http<PutJson, Json>>::html_form(), None);
+    }
+
+    #[test]
+    fn only_validating_and_submitting_are_pending() {
+        assert!(!FormStatus::<()>::Idle.is_pending());
+        assert!(FormStatus::<()>::Validating.is_pending());
+        assert!(FormStatus::<()>::Submitting.is_pending());
+        assert!(!FormStatus::Succeeded(()).is_pending());
+        assert!(!FormStatus::<()>::Failed(String::from("error")).is_pending());
+    }
+
+    #[test]
+    fn with_output_keeps_the_lifecycle() {
+        assert_eq!(FormStatus::Idle.with_output(Some(1)), FormStatus::Idle);
+        assert_eq!(
+            FormStatus::Validating.with_output(Some(1)),
+            FormStatus::Validating
+        );
+        assert_eq!(
+            FormStatus::Submitting.with_output(Some(1)),
+            FormStatus::Submitting
+        );
+        assert_eq!(
+            FormStatus::Failed(String::from("error")).with_output(Some(1)),
+            FormStatus::Failed(String::from("error"))
+        );
+    }
+
+    #[test]
+    fn with_output_gives_succeeded_the_output() {
+        let status = FormStatus::Succeeded(()).with_output(Some(1));
+        assert_eq!(status, FormStatus::Succeeded(1));
+        assert!(status.is_succeeded());
+        assert_eq!(status.output(), Some(&1));
+    }
+
+    #[test]
+    fn succeeded_without_output_is_idle() {
+        let status = FormStatus::Succeeded(()).with_output(None::<i32>);
+        assert_eq!(status, FormStatus::Idle);
+        assert!(!status.is_pending());
+        assert_eq!(status.output(), None);
+    }
+
+    #[test]
+    fn status_follows_the_submission() {
+        let status = ArcRwSignal::new(FormStatus::Idle);
+        let output = ArcRwSignal::new(None);
+        let typed = status_with_output(&status, &output);
+        assert_eq!(typed.get_untracked(), FormStatus::Idle);
+
+        status.set(FormStatus::Validating);
+        assert!(typed.get_untracked().is_pending());
+        status.set(FormStatus::Submitting);
+        assert!(typed.get_untracked().is_pending());
+
+        output.set(Some(String::from("saved")));
+        status.set(FormStatus::Succeeded(()));
+        assert_eq!(
+            typed.get_untracked(),
+            FormStatus::Succeeded(String::from("saved"))
+        );
+
+        // a new submission hides the last output until it responds
+        status.set(FormStatus::Submitting);
+        assert_eq!(typed.get_untracked(), FormStatus::Submitting);
+        status.set(FormStatus::Failed(String::from("error")));
+        assert_eq!(
+            typed.get_untracked().err_msg(),
+            Some(&String::from("error"))
+        );
+    }
+}
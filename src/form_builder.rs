@@ -1,39 +1,45 @@
 use crate::{
     controls::{
+        submit::{submit_intent, INTENT_NAME},
         BuilderCxFn, BuilderFn, BuiltControlData, BuiltVanityControlData, ControlBuilder,
         ControlData, ControlRenderData, FieldSetter, ParseFn, RenderFn, ValidationCb, ValidationFn,
         ValidationState, VanityControlBuilder, VanityControlData,
     },
-    form::{Form, FormToolData, FormValidator},
+    form::{
+        ActionFormServerFn, Form, FormProtocol, FormServerFn, FormStatus, FormToolData,
+        FormValidator, IntentValidation, WatchResponses,
+    },
     styles::FormStyle,
 };
 use leptos::{
     form::ActionForm,
     prelude::*,
     reactive::wrappers::write::{IntoSignalSetter, SignalSetter},
-    server_fn::{
-        client::Client,
-        codec::{Json, PostUrl},
-        request::ClientReq,
-        Http, ServerFn,
-    },
     *,
 };
-use serde::de::DeserializeOwned;
-use std::sync::Arc;
+use std::{fmt::Display, future::Future, pin::Pin, sync::Arc};
 use web_sys::{FormData, SubmitEvent};
 
+/// A hook that runs before the form data is sent to the server.
+type BeforeSubmitFn<FD> = dyn Fn(FD) -> Pin<Box<dyn Future<Output = Result<FD, String>>>>;
+/// Converts the form data and submit intent into the server function's input.
+type ToServerFn<FD, ServFn> = dyn Fn(FD, Option<String>) -> ServFn;
+
 /// A builder for laying out forms.
 ///
 /// This builder allows you to specify what components should make up the form.
 pub struct FormBuilder<FD: FormToolData> {
     pub(crate) cx: Arc<FD::Context>,
     /// The list of [`ValidationFn`]s.
-    pub(crate) validations: Vec<Arc<dyn ValidationFn<FD>>>,
+    pub(crate) validations: Vec<IntentValidation<FD>>,
     /// The list of functions that will render the form.
     pub(crate) render_fns: Vec<Box<dyn RenderFn<FD::Style, FD>>>,
     /// The list of styling attributes applied on the form level.
     pub(crate) styles: Vec<<FD::Style as FormStyle>::StylingAttributes>,
+    /// The submission status of the form, shared with all groups.
+    pub(crate) status: ArcRwSignal<FormStatus>,
+    /// The hooks to run before the form is submitted, in order.
+    pub(crate) before_submit: Vec<Arc<BeforeSubmitFn<FD>>>,
 }
 
 impl<FD: FormToolData> FormBuilder<FD> {
@@ -44,17 +50,21 @@ impl<FD: FormToolData> FormBuilder<FD> {
             validations: Vec::new(),
             render_fns: Vec::new(),
             styles: Vec::new(),
+            status: ArcRwSignal::new(FormStatus::Idle),
+            before_submit: Vec::new(),
         }
     }
 
     /// Creates a new [`FormBuilder`] with the given Arc'ed context, for
     //// building a form group.
-    pub(crate) fn new_group(cx: Arc<FD::Context>) -> Self {
+    pub(crate) fn new_group(cx: Arc<FD::Context>, status: ArcRwSignal<FormStatus>) -> Self {
         FormBuilder {
             cx,
             validations: Vec::new(),
             render_fns: Vec::new(),
             styles: Vec::new(),
+            status,
+            before_submit: Vec::new(),
         }
     }
 
@@ -64,6 +74,29 @@ impl<FD: FormToolData> FormBuilder<FD> {
         self
     }
 
+    /// Adds a hook that runs before the form is submitted.
+    ///
+    /// The hook is given the form data after it passes validation, and
+    /// returns the data that should be sent to the server. This can be used to
+    /// ask the user for confirmation, add data fetched from elsewhere, or
+    /// normalize fields. The returned data is also written back to the form.
+    ///
+    /// If the hook returns an `Err`, the form is not submitted and the form's
+    /// status is set to [`FormStatus::Failed`] with the error.
+    ///
+    /// Hooks only run for forms built with `get_form` or `get_action_form`,
+    /// in the order they were added. For an action form with hooks, the
+    /// action is dispatched with the data from the hooks, instead of the data
+    /// from the html form.
+    pub fn before_submit<Fut>(mut self, hook: impl Fn(FD) -> Fut + 'static) -> Self
+    where
+        Fut: Future<Output = Result<FD, String>> + 'static,
+    {
+        self.before_submit
+            .push(Arc::new(move |fd| Box::pin(hook(fd))));
+        self
+    }
+
     /// Adds a new vanity control to the form.
     pub(crate) fn new_vanity<C: VanityControlData<FD> + Default>(
         mut self,
@@ -173,7 +206,8 @@ impl<FD: FormToolData> FormBuilder<FD> {
                 validation_fn
             };
 
-            self.validations.push(validation_fn);
+            self.validations
+                .push((validation_fn, built_control_data.intents.clone()));
         }
 
         let cx = self.cx.clone();
@@ -200,10 +234,14 @@ impl<FD: FormToolData> FormBuilder<FD> {
             parse_fn,
             unparse_fn,
             validation_fn,
+            intents,
             show_when,
+            revalidate_on_change,
         } = control_data;
 
         let (validation_signal, validation_signal_set) = signal(ValidationState::Passed);
+        // whether the user has entered a value in this control
+        let touched = StoredValue::new(false);
         let validation_fn_clone = validation_fn.clone();
         let initial_value = unparse_fn(fd.with_untracked(|fd| getter(fd)));
         let (value_getter, value_setter) = signal(initial_value);
@@ -215,13 +253,18 @@ impl<FD: FormToolData> FormBuilder<FD> {
 
             let fd = fd.get_untracked();
 
-            // rerun validation if it is failing
-            if validation_signal.get_untracked().is_validation_err() {
+            // rerun validation if it is failing, or if it depends on other
+            // fields that may have changed
+            let depends_on_changes = revalidate_on_change && touched.get_value();
+            if depends_on_changes || validation_signal.get_untracked().is_validation_err() {
                 if let Some(ref validation_fn) = validation_fn_clone {
-                    let validation_result = validation_fn(&fd);
-                    // if validation succeeds this time, resolve the validation error
-                    if validation_result.is_ok() {
-                        validation_signal_set.set(ValidationState::Passed);
+                    match validation_fn(&fd) {
+                        // if validation succeeds this time, resolve the validation error
+                        Ok(()) => validation_signal_set.set(ValidationState::Passed),
+                        Err(e) if depends_on_changes => {
+                            validation_signal_set.set(ValidationState::ValidationError(e))
+                        }
+                        Err(_) => {}
                     }
                 }
             }
@@ -234,7 +277,7 @@ impl<FD: FormToolData> FormBuilder<FD> {
         let validation_fn_clone = validation_fn.clone();
         let cloned_show_when = show_when.clone();
         let cloned_cx = cx.clone();
-        let validation_cb = move || {
+        let validation_cb = move |intent: Option<&str>| {
             // validation for non-visible fields always succeeds
             if let Some(ref show_when) = cloned_show_when {
                 if !show_when(fd.into(), cloned_cx.clone()) {
@@ -250,6 +293,11 @@ impl<FD: FormToolData> FormBuilder<FD> {
                 return false;
             }
 
+            // skip validation if it doesn't run for this submission's intent
+            if !intents.runs_for(intent) {
+                return true;
+            }
+
             // run the validation function on the value now
             let validation_fn = match validation_fn_clone {
                 Some(ref v) => v,
@@ -275,6 +323,10 @@ impl<FD: FormToolData> FormBuilder<FD> {
             setter,
             fd,
         );
+        let value_setter = SignalSetter::map(move |value| {
+            touched.set_value(true);
+            value_setter.set(value);
+        });
 
         let view = move || {
             C::render_control(
@@ -339,28 +391,133 @@ impl<FD: FormToolData> FormBuilder<FD> {
         value_setter.into_signal_setter()
     }
 
+    /// Keeps the form's status and output in sync with the state of the
+    /// action.
+    fn track_action_status<I, O, E>(
+        action: Action<I, Result<O, E>>,
+        status: ArcRwSignal<FormStatus>,
+        output: ArcRwSignal<Option<O>>,
+    ) where
+        I: 'static,
+        O: Clone + Send + Sync + 'static,
+        E: Display + 'static,
+    {
+        Effect::new(move |_| {
+            let new_status = if action.pending().get() {
+                FormStatus::Submitting
+            } else {
+                action.value().with(|value| match value {
+                    None => FormStatus::Idle,
+                    Some(Ok(value)) => {
+                        output.set(Some(value.clone()));
+                        FormStatus::Succeeded(())
+                    }
+                    Some(Err(e)) => FormStatus::Failed(e.to_string()),
+                })
+            };
+            status.set(new_status);
+        });
+    }
+
+    /// Creates a [`WatchResponses`] for the action, which calls back with
+    /// each of its responses.
+    fn watch_responses<I, O, E>(action: Action<I, Result<O, E>>) -> WatchResponses<O, E>
+    where
+        I: 'static,
+        O: 'static,
+        E: 'static,
+    {
+        Arc::new(move |on_response| {
+            Effect::watch(
+                move || action.version().get(),
+                move |_, _, _| {
+                    action.value().with_untracked(|value| {
+                        if let Some(response) = value {
+                            on_response(response);
+                        }
+                    })
+                },
+                false,
+            );
+        })
+    }
+
+    /// Wraps the rendered controls in the form's frame, with the form level
+    /// error after them.
+    fn frame_with_error(
+        fs: &FD::Style,
+        views: Vec<AnyView>,
+        styles: Vec<<FD::Style as FormStyle>::StylingAttributes>,
+        status: &ArcRwSignal<FormStatus>,
+    ) -> AnyView {
+        let error = fs.form_error(status.read_only().into());
+        fs.form_frame(ControlRenderData {
+            data: view! {
+                {views}
+                {error}
+            }
+            .into_any(),
+            styles,
+        })
+    }
+
+    /// Runs the before submit hooks, then dispatches the action with the
+    /// resulting data.
+    fn dispatch_after_hooks<ServFn>(
+        hooks: &Arc<Vec<Arc<BeforeSubmitFn<FD>>>>,
+        to_server_fn: &Arc<ToServerFn<FD, ServFn>>,
+        intent: Option<String>,
+        action: ServerAction<ServFn>,
+        fd: RwSignal<FD>,
+        status: ArcRwSignal<FormStatus>,
+    ) where
+        ServFn: FormServerFn,
+    {
+        if hooks.is_empty() {
+            let server_fn = to_server_fn(fd.get_untracked(), intent);
+            status.set(FormStatus::Submitting);
+            (*action).dispatch(server_fn);
+            return;
+        }
+
+        let hooks = hooks.clone();
+        let to_server_fn = to_server_fn.clone();
+        let data = fd.get_untracked();
+        task::spawn_local(async move {
+            let mut data = data;
+            for hook in hooks.iter() {
+                data = match hook(data).await {
+                    Ok(data) => data,
+                    Err(e) => {
+                        status.set(FormStatus::Failed(e));
+                        return;
+                    }
+                };
+            }
+
+            fd.set(data.clone());
+            status.set(FormStatus::Submitting);
+            (*action).dispatch(to_server_fn(data, intent));
+        });
+    }
+
     /// Builds the direct send version of the form.
-    pub(crate) fn build_form<ServFn, F: Fn(SubmitEvent, RwSignal<FD>) + 'static>(
+    ///
+    /// `to_server_fn` converts the form data and the submission's intent
+    /// into the server function's input.
+    pub(crate) fn build_form<ServFn, F>(
         self,
         action: ServerAction<ServFn>,
         on_submit: F,
+        to_server_fn: impl Fn(FD, Option<String>) -> ServFn + 'static,
         fd: FD,
         fs: FD::Style,
-    ) -> Form<FD>
+    ) -> Form<FD, ServFn::Output, ServFn::Error>
     where
-        ServFn: DeserializeOwned
-            + ServerFn<Protocol = Http<PostUrl, Json>>
-            + From<FD>
-            + Clone
-            + Send
-            + Sync
-            + 'static,
-        <<ServFn::Client as Client<ServFn::Error>>::Request as ClientReq<ServFn::Error>>::FormData:
-            From<FormData>,
-        ServFn::Output: Send + Sync + 'static,
-        ServFn::Error: Send + Sync + 'static,
-        <ServFn as ServerFn>::Client: Client<<ServFn as ServerFn>::Error>,
+        F: Fn(SubmitEvent, RwSignal<FD>, Option<String>) + 'static,
+        ServFn: FormServerFn,
     {
+        let initial = Arc::new(fd.clone());
         let fd = RwSignal::new(fd);
         let fs = Arc::new(fs);
 
@@ -370,69 +527,91 @@ impl<FD: FormToolData> FormBuilder<FD> {
             .map(|r_fn| r_fn(fs.clone(), fd))
             .unzip();
 
-        let elements = fs.form_frame(ControlRenderData {
-            data: views.into_any(),
-            styles: self.styles,
-        });
+        let status = self.status;
+        let elements = Self::frame_with_error(&fs, views, self.styles, &status);
+        let output = ArcRwSignal::new(None);
+        Self::track_action_status(*action, status.clone(), output.clone());
+        let watch_responses = Self::watch_responses(*action);
+        let before_submit = Arc::new(self.before_submit);
+        let to_server_fn: Arc<ToServerFn<FD, ServFn>> = Arc::new(to_server_fn);
 
+        let submit_status = status.clone();
         let on_submit = move |ev: SubmitEvent| {
             if ev.default_prevented() {
                 return;
             }
             ev.prevent_default();
 
+            // don't submit again while a submission is in flight
+            if submit_status.with_untracked(FormStatus::is_pending) {
+                return;
+            }
+            submit_status.set(FormStatus::Validating);
+
+            let intent = submit_intent(&ev);
             let mut failed = false;
             for validation in validation_cbs.iter().flatten() {
-                if !validation() {
+                if !validation(intent.as_deref()) {
                     failed = true;
                 }
             }
             if failed {
+                submit_status.set(FormStatus::Idle);
                 return;
             }
 
-            on_submit(ev, fd);
-
-            let server_fn = ServFn::from(fd.get_untracked());
-            (*action).dispatch(server_fn);
+            on_submit(ev, fd, intent.clone());
+            Self::dispatch_after_hooks(
+                &before_submit,
+                &to_server_fn,
+                intent,
+                action,
+                fd,
+                submit_status.clone(),
+            );
         };
 
+        // without JavaScript, the html form is sent straight to the server
+        // function, if it accepts what an html form can send
+        let (method, enctype) = <ServFn::Protocol as FormProtocol>::html_form().unzip();
+        let form_action = method.map(|_| ServFn::url());
         let view = view! {
-            <ActionForm action=action on:submit:capture=on_submit>
+            <form action=form_action method=method enctype=enctype on:submit=on_submit>
                 {elements}
-            </ActionForm>
+            </form>
         }
         .into_any();
 
         Form {
             fd,
             validations: self.validations,
+            status,
+            output,
+            watch_responses: Some(watch_responses),
+            initial,
             view,
         }
     }
 
     /// Builds the action form version of the form.
-    pub(crate) fn build_action_form<ServFn, F: Fn(SubmitEvent, RwSignal<FD>) + 'static>(
+    ///
+    /// `to_server_fn` converts the form data and the submission's intent
+    /// into the server function's input when the
+    /// [`before_submit`](Self::before_submit) hooks run. Otherwise, the
+    /// `ActionForm` sends the html form data, which has the intent in it.
+    pub(crate) fn build_action_form<ServFn, OutputProtocol, F>(
         self,
         action: ServerAction<ServFn>,
         on_submit: F,
+        to_server_fn: impl Fn(FD, Option<String>) -> ServFn + 'static,
         fd: FD,
         fs: FD::Style,
-    ) -> Form<FD>
+    ) -> Form<FD, ServFn::Output, ServFn::Error>
     where
-        ServFn: DeserializeOwned
-            + ServerFn<Protocol = Http<PostUrl, Json>>
-            + From<FD>
-            + Clone
-            + Send
-            + Sync
-            + 'static,
-        <<ServFn::Client as Client<ServFn::Error>>::Request as ClientReq<ServFn::Error>>::FormData:
-            From<FormData>,
-        ServFn::Output: Send + Sync + 'static,
-        ServFn::Error: Send + Sync + 'static,
-        <ServFn as ServerFn>::Client: Client<<ServFn as ServerFn>::Error>,
+        ServFn: ActionFormServerFn<OutputProtocol>,
+        F: Fn(SubmitEvent, RwSignal<FD>, Option<String>) + 'static,
     {
+        let initial = Arc::new(fd.clone());
         let fd = RwSignal::new(fd);
         let fs = Arc::new(fs);
 
@@ -442,28 +621,60 @@ impl<FD: FormToolData> FormBuilder<FD> {
             .map(|r_fn| r_fn(fs.clone(), fd))
             .unzip();
 
-        let elements = fs.form_frame(ControlRenderData {
-            data: views.into_any(),
-            styles: self.styles,
-        });
+        let status = self.status;
+        let elements = Self::frame_with_error(&fs, views, self.styles, &status);
+        let output = ArcRwSignal::new(None);
+        Self::track_action_status(*action, status.clone(), output.clone());
+        let watch_responses = Self::watch_responses(*action);
+        let before_submit = Arc::new(self.before_submit);
+        let to_server_fn: Arc<ToServerFn<FD, ServFn>> = Arc::new(to_server_fn);
 
+        let submit_status = status.clone();
         let on_submit = move |ev: SubmitEvent| {
             if ev.default_prevented() {
                 return;
             }
 
+            // don't submit again while a submission is in flight
+            if submit_status.with_untracked(FormStatus::is_pending) {
+                ev.prevent_default();
+                return;
+            }
+            submit_status.set(FormStatus::Validating);
+
+            let intent = submit_intent(&ev);
             let mut failed = false;
             for validation in validation_cbs.iter().flatten() {
-                if !validation() {
+                if !validation(intent.as_deref()) {
                     failed = true;
                 }
             }
             if failed {
                 ev.prevent_default();
+                submit_status.set(FormStatus::Idle);
                 return;
             }
 
-            on_submit(ev, fd);
+            if before_submit.is_empty() {
+                // the `ActionForm` dispatches the action after this, so the
+                // form is pending until the action is
+                submit_status.set(FormStatus::Submitting);
+                on_submit(ev, fd, intent);
+                return;
+            }
+
+            // the hooks may change the data, so the action is dispatched
+            // directly instead of by the `ActionForm`
+            ev.prevent_default();
+            on_submit(ev, fd, intent.clone());
+            Self::dispatch_after_hooks(
+                &before_submit,
+                &to_server_fn,
+                intent,
+                action,
+                fd,
+                submit_status.clone(),
+            );
         };
 
         let view = view! {
@@ -476,6 +687,131 @@ impl<FD: FormToolData> FormBuilder<FD> {
         Form {
             fd,
             validations: self.validations,
+            status,
+            output,
+            watch_responses: Some(watch_responses),
+            initial,
+            view,
+        }
+    }
+
+    /// Builds the multipart version of the form.
+    ///
+    /// The html form's data, including any picked files, is sent to the
+    /// `action` as [`FormData`].
+    pub(crate) fn build_multipart_form<O, E, F>(
+        self,
+        action: Action<FormData, Result<O, E>>,
+        on_submit: F,
+        fd: FD,
+        fs: FD::Style,
+    ) -> Form<FD, O, E>
+    where
+        O: Clone + Send + Sync + 'static,
+        E: Display + 'static,
+        F: Fn(SubmitEvent, RwSignal<FD>) + 'static,
+    {
+        let initial = Arc::new(fd.clone());
+        let fd = RwSignal::new(fd);
+        let fs = Arc::new(fs);
+
+        let (views, validation_cbs): (Vec<_>, Vec<_>) = self
+            .render_fns
+            .into_iter()
+            .map(|r_fn| r_fn(fs.clone(), fd))
+            .unzip();
+
+        let status = self.status;
+        let elements = Self::frame_with_error(&fs, views, self.styles, &status);
+        let output = ArcRwSignal::new(None);
+        Self::track_action_status(action, status.clone(), output.clone());
+        let watch_responses = Self::watch_responses(action);
+        let before_submit = Arc::new(self.before_submit);
+        let form_ref = NodeRef::<html::Form>::new();
+
+        let submit_status = status.clone();
+        let on_submit = move |ev: SubmitEvent| {
+            if ev.default_prevented() {
+                return;
+            }
+            ev.prevent_default();
+
+            // don't submit again while a submission is in flight
+            if submit_status.with_untracked(FormStatus::is_pending) {
+                return;
+            }
+            submit_status.set(FormStatus::Validating);
+
+            let intent = submit_intent(&ev);
+            let mut failed = false;
+            for validation in validation_cbs.iter().flatten() {
+                if !validation(intent.as_deref()) {
+                    failed = true;
+                }
+            }
+            if failed {
+                submit_status.set(FormStatus::Idle);
+                return;
+            }
+
+            on_submit(ev, fd);
+
+            let Some(form) = form_ref.get_untracked() else {
+                submit_status.set(FormStatus::Idle);
+                return;
+            };
+            let hooks = before_submit.clone();
+            let status = submit_status.clone();
+            task::spawn_local(async move {
+                if !hooks.is_empty() {
+                    let mut data = fd.get_untracked();
+                    for hook in hooks.iter() {
+                        data = match hook(data).await {
+                            Ok(data) => data,
+                            Err(e) => {
+                                status.set(FormStatus::Failed(e));
+                                return;
+                            }
+                        };
+                    }
+                    fd.set(data);
+                    // let the controls render the new data before the html
+                    // form is read
+                    task::tick().await;
+                }
+
+                let form_data = match FormData::new_with_form(&form) {
+                    Ok(form_data) => form_data,
+                    Err(_) => {
+                        status.set(FormStatus::Failed(String::from(
+                            "Could not read the form data",
+                        )));
+                        return;
+                    }
+                };
+                if let Some(intent) = intent {
+                    // the submitter is not included in the form data
+                    let _ = form_data.append_with_str(INTENT_NAME, &intent);
+                }
+                status.set(FormStatus::Submitting);
+                action.dispatch_local(form_data);
+            });
+        };
+
+        let view = view! {
+            <form node_ref=form_ref enctype="multipart/form-data" on:submit=on_submit>
+                {elements}
+            </form>
+        }
+        .into_any();
+
+        Form {
+            fd,
+            validations: self.validations,
+            status,
+            output,
+            watch_responses: Some(watch_responses),
+            initial,
             view,
         }
     }
@@ -488,6 +824,7 @@ impl<FD: FormToolData> FormBuilder<FD> {
         fd: FD,
         fs: FD::Style,
     ) -> Form<FD> {
+        let initial = Arc::new(fd.clone());
         let fd = RwSignal::new(fd);
         let fs = Arc::new(fs);
 
@@ -503,10 +840,11 @@ impl<FD: FormToolData> FormBuilder<FD> {
         });
 
         let on_submit = move |ev: SubmitEvent| {
+            let intent = submit_intent(&ev);
             let mut failed = false;
 
             for validation in validation_cbs.iter().flatten() {
-                if !validation() {
+                if !validation(intent.as_deref()) {
                     failed = true;
                 }
             }
@@ -529,12 +867,17 @@ impl<FD: FormToolData> FormBuilder<FD> {
         Form {
             fd,
             validations: self.validations,
+            status: self.status,
+            output: ArcRwSignal::new(None),
+            watch_responses: None,
+            initial,
             view,
         }
     }
 
     /// builds just the controls of the form.
     pub(crate) fn build_form_controls(self, fd: FD, fs: FD::Style) -> Form<FD> {
+        let initial = Arc::new(fd.clone());
         let fd = RwSignal::new(fd);
         let fs = Arc::new(fs);
 
@@ -552,6 +895,10 @@ impl<FD: FormToolData> FormBuilder<FD> {
         Form {
             fd,
             validations: self.validations,
+            status: self.status,
+            output: ArcRwSignal::new(None),
+            watch_responses: None,
+            initial,
             view,
         }
     }
@@ -0,0 +1,85 @@
+/// An enum that can be picked from a list of options, like with a select or
+/// radio buttons.
+///
+/// Each variant has a value, which is what is sent with the form, and a
+/// label, which is what is shown to the user.
+///
+/// With the `derive` feature, this can be derived for enums with only unit
+/// variants. The value defaults to the variant's name, and the label to the
+/// variant's name split into words. These can be changed with
+/// `#[form(value = "...")]` and `#[form(label = "...")]` on the variant.
+///
+/// ```ignore
+/// #[derive(Clone, PartialEq, FormEnum)]
+/// enum Size {
+///     Small,
+///     #[form(label = "Medium (most popular)")]
+///     Medium,
+///     #[form(value = "xl", label = "Extra large")]
+///     ExtraLarge,
+/// }
+/// ```
+pub trait FormEnum: Clone + 'static {
+    /// Gets all the variants, in the order they should be shown.
+    fn variants() -> &'static [Self];
+
+    /// Gets the value of this variant, which is sent with the form.
+    ///
+    /// This must be different for each variant.
+    fn value(&self) -> &'static str;
+
+    /// Gets the label of this variant, which is shown to the user.
+    fn label(&self) -> &'static str;
+
+    /// Gets the variant with the given value.
+    fn from_value(value: &str) -> Option<Self> {
+        Self::variants()
+            .iter()
+            .find(|v| v.value() == value)
+            .cloned()
+    }
+}
+
+/// A field that holds a [`FormEnum`], which can be the enum itself or an
+/// [`Option`] of it.
+///
+/// For `Option`s, an empty value is `None`.
+pub trait EnumField<E: FormEnum>: Sized {
+    /// Whether the field can be left unpicked.
+    const OPTIONAL: bool;
+
+    /// Parses the field from a variant's value.
+    fn from_enum_value(value: &str) -> Result<Self, String>;
+
+    /// Gets the value of the field's variant.
+    fn to_enum_value(&self) -> String;
+}
+
+impl<E: FormEnum> EnumField<E> for E {
+    const OPTIONAL: bool = false;
+
+    fn from_enum_value(value: &str) -> Result<Self, String> {
+        E::from_value(value).ok_or_else(|| String::from("Please pick an option"))
+    }
+
+    fn to_enum_value(&self) -> String {
+        self.value().to_string()
+    }
+}
+
+impl<E: FormEnum> EnumField<E> for Option<E> {
+    const OPTIONAL: bool = true;
+
+    fn from_enum_value(value: &str) -> Result<Self, String> {
+        if value.is_empty() {
+            return Ok(None);
+        }
+        <E as EnumField<E>>::from_enum_value(value).map(Some)
+    }
+
+    fn to_enum_value(&self) -> String {
+        self.as_ref()
+            .map(<E as EnumField<E>>::to_enum_value)
+            .unwrap_or_default()
+    }
+}
@@ -3,9 +3,16 @@
 pub mod controls;
 mod form;
 mod form_builder;
+mod form_enum;
 pub mod styles;
 mod validation_builder;
 
-pub use form::{Form, FormToolData, FormValidator};
+pub use form::{
+    ActionFormServerFn, Form, FormHandle, FormProtocol, FormServerFn, FormStatus, FormToolData,
+    FormValidator,
+};
 pub use form_builder::FormBuilder;
+pub use form_enum::{EnumField, FormEnum};
+#[cfg(feature = "derive")]
+pub use leptos_form_tool_derive::FormEnum;
 pub use validation_builder::ValidationBuilder;
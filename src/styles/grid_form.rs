@@ -7,14 +7,39 @@
 //! the root of the project on git).
 
 use super::FormStyle;
-use crate::controls::{
-    button::ButtonData, checkbox::CheckboxData, heading::HeadingData, hidden::HiddenData,
-    output::OutputData, radio_buttons::RadioButtonsData, select::SelectData, slider::SliderData,
-    spacer::SpacerData, stepper::StepperData, submit::SubmitData, text_area::TextAreaData,
-    text_input::TextInputData, ControlRenderData, UpdateEvent, ValidationState,
+use crate::{
+    controls::{
+        button::ButtonData,
+        checkbox::CheckboxData,
+        checkbox_group::CheckboxGroupData,
+        combobox::ComboboxData,
+        date_input::DateInputData,
+        file_input::{FileInputData, UploadedFile},
+        heading::HeadingData,
+        hidden::HiddenData,
+        multi_select::MultiSelectData,
+        number_input::NumberInputData,
+        other_text, other_value,
+        otp_input::OtpInputData,
+        output::OutputData,
+        password::{PasswordData, PasswordStrength},
+        radio_buttons::RadioButtonsData,
+        range_slider::RangeSliderData,
+        select::{SelectData, SelectOption},
+        slider::SliderData,
+        spacer::SpacerData,
+        stepper::StepperData,
+        submit::{SubmitData, INTENT_NAME},
+        switch::SwitchData,
+        tags_input::TagsInputData,
+        text_area::TextAreaData,
+        text_input::{apply_mask, masked_caret, strip_mask, TextInputData},
+        ControlRenderData, OptionLayout, UpdateEvent, ValidationState,
+    },
+    form::FormStatus,
 };
-use leptos::{prelude::*, reactive::wrappers::write::SignalSetter};
-use web_sys::MouseEvent;
+use leptos::{prelude::*, reactive::wrappers::write::SignalSetter, wasm_bindgen::JsCast};
+use web_sys::{HtmlInputElement, HtmlOptionElement, KeyboardEvent, MouseEvent};
 
 /// Styling attributes for the [`GridFormStyle`].
 #[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
@@ -107,17 +132,45 @@ impl FormStyle for GridFormStyle {
         self.common_component(&control.styles, "heading_parent", view)
     }
 
+    fn form_error(&self, status: Signal<FormStatus>) -> AnyView {
+        let view = view! {
+            <Show when=move || status.with(FormStatus::is_failed)>
+                <p class="form_error" role="alert">
+                    {move || status.with(|s| s.err_msg().cloned())}
+                </p>
+            </Show>
+        }
+        .into_any();
+
+        self.common_component(&[], "form_error_parent", view)
+    }
+
     fn submit(
         &self,
         control: ControlRenderData<Self, SubmitData>,
         value_getter: Option<Signal<String>>,
+        status: Signal<FormStatus>,
     ) -> AnyView {
         let title = move || value_getter.map(|v| v.get()).unwrap_or_default();
+        let pending = move || status.get().is_pending();
 
         self.common_component(
             &control.styles,
             "submit_parent",
-            view! { <input type="submit" value=title class="form_submit"/> }.into_any(),
+            view! {
+                <button
+                    type="submit"
+                    name=control.data.intent.as_ref().map(|_| INTENT_NAME)
+                    value=control.data.intent.clone()
+                    class="form_submit"
+                    class=("form_submit_pending", pending)
+                    disabled=pending
+                    aria-busy=move || pending().to_string()
+                >
+                    {title}
+                </button>
+            }
+            .into_any(),
         )
     }
 
@@ -178,6 +231,9 @@ impl FormStyle for GridFormStyle {
         validation_state: Signal<ValidationState>,
     ) -> AnyView {
         let update_event = control.data.update_event;
+        let mask = control.data.mask.clone();
+        let display_mask = mask.clone();
+        let input_mask = mask.clone();
         let input = view! {
             <input
                 type=control.data.input_type
@@ -186,10 +242,20 @@ impl FormStyle for GridFormStyle {
                 placeholder=control.data.placeholder.clone()
                 class="form_input"
                 class=("form_input_invalid", move || validation_state.get().is_err())
-                prop:value=move || { value_getter.get() }
+                prop:value=move || {
+                    let value = value_getter.get();
+                    match &display_mask {
+                        Some(mask) => apply_mask(mask, &strip_mask(mask, &value)),
+                        None => value,
+                    }
+                }
                 on:input:target=move |ev| {
+                    let target = ev.target();
+                    if let Some(mask) = &input_mask {
+                        format_masked(&target, mask);
+                    }
                     if update_event == UpdateEvent::OnInput {
-                        value_setter.set(ev.target().value())
+                        value_setter.set(target.value())
                     }
                 }
                 on:focusout:target=move |ev| {
@@ -275,35 +341,80 @@ impl FormStyle for GridFormStyle {
         value_setter: SignalSetter<String>,
         validation_state: Signal<ValidationState>,
     ) -> AnyView {
-        let buttons_view = control
-            .data
-            .options
-            .iter()
-            .map(|(display, value)| {
-                let display = display.clone();
-                let value = value.clone();
-                let value_clone = value.clone();
-                let value_clone2 = value.clone();
-                view! {
+        let inline = control.data.layout == OptionLayout::Inline;
+        let name = control.data.name.clone();
+        let options = control.data.options;
+        let (other_picked, other_input) = other_state(value_getter);
+        let buttons_view = move || {
+            options
+                .get()
+                .into_iter()
+                .map(|option| {
+                    let id = format!("{}_{}", name, option.value);
+                    let checked_value = option.value.clone();
+                    let picked_value = option.value.clone();
+                    let description = option.description.map(|description| {
+                        view! { <span class="form_radio_description">{description}</span> }
+                    });
+                    view! {
+                        <span
+                            class="form_radio_option"
+                            class:form_radio_option_disabled=option.disabled
+                        >
+                            <input
+                                type="radio"
+                                id=id.clone()
+                                name=name.clone()
+                                value=option.value
+                                disabled=option.disabled
+                                prop:checked=move || {
+                                    !other_picked.get() && value_getter.get() == checked_value
+                                }
+                                on:input=move |ev| {
+                                    let new_value = event_target_checked(&ev);
+                                    if new_value {
+                                        other_picked.set(false);
+                                        value_setter.set(picked_value.clone());
+                                    }
+                                }
+                            />
+                            <label for=id>{option.display}</label>
+                            {description}
+                        </span>
+                    }
+                })
+                .collect_view()
+        };
+
+        let other_view = control.data.other_option.clone().map(|label| {
+            let id = format!("{}_{}", control.data.name, OTHER_OPTION);
+            let input = other_input_view(
+                format!("{}_other", control.data.name),
+                label.clone(),
+                other_picked,
+                other_input,
+                value_setter,
+            );
+            view! {
+                <span class="form_radio_option">
                     <input
                         type="radio"
-                        id=value.clone()
+                        id=id.clone()
                         name=control.data.name.clone()
-                        value=value.clone()
-                        prop:checked=move || { value_getter.get() == value_clone }
+                        value=OTHER_OPTION
+                        prop:checked=move || other_picked.get()
                         on:input=move |ev| {
-                            let new_value = event_target_checked(&ev);
-                            if new_value {
-                                value_setter.set(value_clone2.clone());
+                            if event_target_checked(&ev) {
+                                other_picked.set(true);
+                                value_setter.set(other_value(&other_input.get_untracked()));
                             }
                         }
                     />
-
-                    <label for=value>{display}</label>
-                    <br/>
-                }
-            })
-            .collect_view();
+                    <label for=id>{label}</label>
+                </span>
+                {input}
+            }
+        });
 
         let view = view! {
             <div>
@@ -314,9 +425,12 @@ impl FormStyle for GridFormStyle {
             </div>
             <div
                 class="form_input"
+                class=("form_radio_inline", inline)
+                class=("form_radio_stacked", !inline)
                 class:form_input_invalid=move || validation_state.get().is_err()
             >
                 {buttons_view}
+                {other_view}
             </div>
         }
         .into_any();
@@ -331,33 +445,67 @@ impl FormStyle for GridFormStyle {
         value_setter: SignalSetter<String>,
         validation_state: Signal<ValidationState>,
     ) -> AnyView {
-        let control_clone = control.clone();
+        let options = control.data.options;
+        let loading = control.data.loading;
+        let error = control.data.error;
+        let (other_picked, other_input) = other_state(value_getter);
         let options_view = move || {
-            control_clone
-            .data
-            .options
-            .get()
-            .iter()
-            .map(|(display, value)| {
-                let display = display.clone();
-                let value = value.clone();
-                view! {
-                    <option value=value.clone() selected=move || { value_getter.get() == *value }>
-                        {display}
-                    </option>
-                }
-            })
-            .collect_view()
+            SelectOption::grouped(options.get())
+                .into_iter()
+                .map(|(group, group_options)| {
+                    let group_options = group_options
+                        .into_iter()
+                        .map(|option| {
+                            let value = option.value.clone();
+                            view! {
+                                <option
+                                    value=option.value
+                                    disabled=option.disabled
+                                    selected=move || !other_picked.get() && value_getter.get() == *value
+                                >
+                                    {option.display}
+                                </option>
+                            }
+                        })
+                        .collect_view();
+                    match group {
+                        Some(group) => {
+                            view! { <optgroup label=group>{group_options}</optgroup> }.into_any()
+                        }
+                        None => group_options.into_any(),
+                    }
+                })
+                .collect_view()
         };
 
         let blank_option_view = control.data.blank_option.map(|display| {
             view! {
-                <option value="" selected=move || { value_getter.get().as_str() == "" }>
+                <option
+                    value=""
+                    selected=move || !other_picked.get() && value_getter.get().as_str() == ""
+                >
                     {display}
                 </option>
             }
         });
 
+        let other_option_view = control.data.other_option.clone().map(|label| {
+            view! {
+                <option value=OTHER_OPTION selected=move || other_picked.get()>
+                    {label}
+                </option>
+            }
+        });
+        let other_input_view = control.data.other_option.clone().map(|label| {
+            other_input_view(
+                format!("{}_other", control.data.name),
+                label,
+                other_picked,
+                other_input,
+                value_setter,
+            )
+        });
+
         let view = view! {
             <div>
                 <label for=control.data.name.clone() class="form_label">
@@ -371,23 +519,299 @@ impl FormStyle for GridFormStyle {
                 class="form_input"
                 class=("form_input_invalid", move || validation_state.get().is_err())
                 on:input=move |ev| {
-                    value_setter.set(event_target_value(&ev));
+                    let value = event_target_value(&ev);
+                    if value == OTHER_OPTION {
+                        other_picked.set(true);
+                        value_setter.set(other_value(&other_input.get_untracked()));
+                    } else {
+                        other_picked.set(false);
+                        value_setter.set(value);
+                    }
                 }
             >
                 {blank_option_view}
                 {options_view}
+                {other_option_view}
             </select>
+            {other_input_view}
+            <Show when=move || loading.get()>
+                <span class="form_select_loading">"Loading..."</span>
+            </Show>
+            <span class="form_select_load_error">{move || error.get()}</span>
         }
         .into_any();
 
         self.common_component(&control.styles, "select_parent", view)
     }
 
+    fn searchable_select(
+        &self,
+        control: ControlRenderData<Self, SelectData>,
+        value_getter: Signal<String>,
+        value_setter: SignalSetter<String>,
+        validation_state: Signal<ValidationState>,
+    ) -> AnyView {
+        // the other option needs its own text input, which the plain select has
+        if control.data.other_option.is_some() {
+            return self.select(control, value_getter, value_setter, validation_state);
+        }
+
+        // the rows have a fixed height, so only the visible ones are rendered
+        const ROW_HEIGHT: i32 = 32;
+        const VISIBLE_ROWS: usize = 8;
+        const OVERSCAN: usize = 4;
+
+        let SelectData {
+            name,
+            label,
+            options,
+            loading,
+            error,
+            blank_option,
+            ..
+        } = control.data;
+        let list_id = format!("{}_options", name);
+        let option_prefix = format!("{}_option", name);
+        let active_prefix = option_prefix.clone();
+
+        let query = RwSignal::new(String::new());
+        let open = RwSignal::new(false);
+        let active = RwSignal::new(None::<usize>);
+        let scroll_top = RwSignal::new(0);
+        let list_ref = NodeRef::<leptos::html::Div>::new();
+
+        // the blank option is kept as the first option, so it can be picked
+        let all_options = Memo::new(move |_| {
+            let mut all = Vec::new();
+            if let Some(ref display) = blank_option {
+                all.push(SelectOption::valued(display, ""));
+            }
+            all.extend(options.get());
+            all
+        });
+        let filtered = Memo::new(move |_| {
+            let query = query.get().to_lowercase();
+            all_options
+                .get()
+                .into_iter()
+                .filter(|option| {
+                    query.is_empty()
+                        || option.display.to_lowercase().contains(&query)
+                        || option.value.to_lowercase().contains(&query)
+                })
+                .collect::<Vec<_>>()
+        });
+        let selected_display = move || {
+            let value = value_getter.get();
+            all_options
+                .with(|all| {
+                    all.iter()
+                        .find(|o| o.value == value)
+                        .map(|o| o.display.clone())
+                })
+                .unwrap_or(value)
+        };
+
+        let scroll_to = move |index: usize| {
+            let Some(list) = list_ref.get_untracked() else {
+                return;
+            };
+            let top = index as i32 * ROW_HEIGHT;
+            let view_height = VISIBLE_ROWS as i32 * ROW_HEIGHT;
+            let current = list.scroll_top();
+            if top < current {
+                list.set_scroll_top(top);
+            } else if top + ROW_HEIGHT > current + view_height {
+                list.set_scroll_top(top + ROW_HEIGHT - view_height);
+            }
+        };
+        let move_active = move |index: usize| {
+            open.set(true);
+            let len = filtered.with_untracked(Vec::len);
+            if len == 0 {
+                return;
+            }
+            let index = index.min(len - 1);
+            active.set(Some(index));
+            scroll_to(index);
+        };
+        let close = move || {
+            open.set(false);
+            active.set(None);
+            query.set(String::new());
+        };
+        let pick = move |option: SelectOption| {
+            if option.disabled {
+                return;
+            }
+            value_setter.set(option.value);
+            close();
+        };
+
+        let on_keydown = move |ev: KeyboardEvent| {
+            let current = active.get_untracked();
+            let is_open = open.get_untracked();
+            match ev.key().as_str() {
+                "ArrowDown" => {
+                    ev.prevent_default();
+                    move_active(current.map_or(0, |i| i + 1));
+                }
+                "ArrowUp" => {
+                    ev.prevent_default();
+                    move_active(current.map_or(0, |i| i.saturating_sub(1)));
+                }
+                "PageDown" => {
+                    ev.prevent_default();
+                    move_active(current.map_or(0, |i| i + VISIBLE_ROWS));
+                }
+                "PageUp" => {
+                    ev.prevent_default();
+                    move_active(current.map_or(0, |i| i.saturating_sub(VISIBLE_ROWS)));
+                }
+                "Home" if is_open => {
+                    ev.prevent_default();
+                    move_active(0);
+                }
+                "End" if is_open => {
+                    ev.prevent_default();
+                    move_active(usize::MAX);
+                }
+                "Enter" => {
+                    let choice = current
+                        .filter(|_| is_open)
+                        .and_then(|i| filtered.with_untracked(|f| f.get(i).cloned()));
+                    if let Some(option) = choice {
+                        // pick the option instead of submitting the form
+                        ev.prevent_default();
+                        pick(option);
+                    }
+                }
+                "Escape" => close(),
+                _ => {}
+            }
+        };
+
+        let rows = move || {
+            let first = (scroll_top.get() / ROW_HEIGHT).max(0) as usize;
+            let start = first.saturating_sub(OVERSCAN);
+            let count = VISIBLE_ROWS + OVERSCAN * 2;
+            filtered.with(|filtered| {
+                filtered
+                    .iter()
+                    .enumerate()
+                    .skip(start)
+                    .take(count)
+                    .map(|(i, option)| {
+                        let picked = option.clone();
+                        let value = option.value.clone();
+                        let group = option.group.clone().map(|group| {
+                            view! { <span class="form_searchable_group">{group}</span> }
+                        });
+                        view! {
+                            <div
+                                id=format!("{}_{}", option_prefix, i)
+                                role="option"
+                                class="form_searchable_option"
+                                class=("form_searchable_option_active", move || active.get() == Some(i))
+                                class:form_searchable_option_disabled=option.disabled
+                                aria-selected=move || value_getter.with(|v| *v == value).to_string()
+                                aria-disabled=option.disabled.to_string()
+                                style:top=format!("{}px", i as i32 * ROW_HEIGHT)
+                                style:height=format!("{}px", ROW_HEIGHT)
+                                on:mousedown=move |ev| {
+                                    // keep the focus on the input
+                                    ev.prevent_default();
+                                    pick(picked.clone());
+                                }
+                            >
+                                {option.display.clone()}
+                                {group}
+                            </div>
+                        }
+                    })
+                    .collect_view()
+            })
+        };
+
+        let view = view! {
+            <div>
+                <label for=name.clone() class="form_label">
+                    {label}
+                </label>
+                <span class="form_error">{move || validation_state.get().take_msg()}</span>
+            </div>
+            <div class="form_searchable_select">
+                <input type="hidden" name=name.clone() prop:value=move || value_getter.get()/>
+                <input
+                    type="text"
+                    id=name
+                    role="combobox"
+                    autocomplete="off"
+                    aria-autocomplete="list"
+                    aria-controls=list_id.clone()
+                    aria-expanded=move || open.get().to_string()
+                    aria-activedescendant=move || {
+                        active.get().map(|i| format!("{}_{}", active_prefix, i))
+                    }
+                    placeholder=selected_display
+                    class="form_input"
+                    class=("form_input_invalid", move || validation_state.get().is_err())
+                    prop:value=move || if open.get() { query.get() } else { selected_display() }
+                    on:focus=move |_| open.set(true)
+                    on:input:target=move |ev| {
+                        query.set(ev.target().value());
+                        if let Some(list) = list_ref.get_untracked() {
+                            list.set_scroll_top(0);
+                        }
+                        move_active(0);
+                    }
+                    on:keydown=on_keydown
+                    on:focusout=move |_| close()
+                />
+                <div
+                    node_ref=list_ref
+                    id=list_id
+                    role="listbox"
+                    class="form_searchable_list"
+                    hidden=move || !open.get()
+                    style:max-height=format!("{}px", VISIBLE_ROWS as i32 * ROW_HEIGHT)
+                    // keep the focus on the input while scrolling
+                    on:mousedown=|ev| ev.prevent_default()
+                    on:scroll=move |_| {
+                        if let Some(list) = list_ref.get_untracked() {
+                            scroll_top.set(list.scroll_top());
+                        }
+                    }
+                >
+                    <div
+                        class="form_searchable_spacer"
+                        style:height=move || {
+                            format!("{}px", filtered.with(Vec::len) as i32 * ROW_HEIGHT)
+                        }
+                    >
+                        {rows}
+                    </div>
+                    <Show when=move || !loading.get() && filtered.with(Vec::is_empty)>
+                        <div class="form_searchable_empty">"No matches"</div>
+                    </Show>
+                </div>
+                <Show when=move || loading.get()>
+                    <span class="form_select_loading">"Loading..."</span>
+                </Show>
+                <span class="form_select_load_error">{move || error.get()}</span>
+            </div>
+        }
+        .into_any();
+
+        self.common_component(&control.styles, "searchable_select_parent", view)
+    }
+
     fn checkbox(
         &self,
         control: ControlRenderData<Self, CheckboxData>,
         value_getter: Signal<bool>,
         value_setter: SignalSetter<bool>,
+        validation_state: Signal<ValidationState>,
     ) -> AnyView {
         let label = control
             .data
@@ -415,6 +839,7 @@ impl FormStyle for GridFormStyle {
                 />
                 <span style="margin: auto 0.5rem;">{label}</span>
             </label>
+            <span class="form_error">{move || validation_state.get().take_msg()}</span>
         }
         .into_any();
 
@@ -455,6 +880,113 @@ impl FormStyle for GridFormStyle {
         self.common_component(&control.styles, "stepper_parent", view)
     }
 
+    fn password(
+        &self,
+        control: ControlRenderData<Self, PasswordData>,
+        value_getter: Signal<String>,
+        value_setter: SignalSetter<String>,
+        validation_state: Signal<ValidationState>,
+    ) -> AnyView {
+        let revealed = RwSignal::new(false);
+        let autocomplete = control.data.autocomplete();
+
+        let strength = control.data.strength.map(|strength| {
+            let shown = move || !value_getter.with(String::is_empty);
+            view! {
+                <Show when=shown>
+                    <div class="form_password_strength">
+                        <meter
+                            min="0"
+                            max=PasswordStrength::MAX_SCORE
+                            low="2"
+                            high="3"
+                            optimum=PasswordStrength::MAX_SCORE
+                            value=move || strength.get().score()
+                        />
+                        <span>{move || strength.get().describe()}</span>
+                    </div>
+                </Show>
+            }
+        });
+
+        let view = view! {
+            <div>
+                <label for=control.data.name.clone() class="form_label">
+                    {control.data.label}
+                </label>
+                <span class="form_error">{move || validation_state.get().take_msg()}</span>
+            </div>
+            <div class="form_password">
+                <input
+                    type=move || if revealed.get() { "text" } else { "password" }
+                    id=control.data.name.clone()
+                    name=control.data.name.clone()
+                    placeholder=control.data.placeholder
+                    autocomplete=autocomplete
+                    class="form_input"
+                    class=("form_input_invalid", move || validation_state.get().is_err())
+                    prop:value=move || value_getter.get()
+                    on:input:target=move |ev| {
+                        value_setter.set(ev.target().value());
+                    }
+                />
+                <button
+                    type="button"
+                    class="form_password_toggle"
+                    aria-controls=control.data.name
+                    aria-pressed=move || revealed.get().to_string()
+                    on:click=move |_| revealed.update(|revealed| *revealed = !*revealed)
+                >
+                    {move || if revealed.get() { "Hide" } else { "Show" }}
+                </button>
+            </div>
+            {strength}
+        }
+        .into_any();
+
+        self.common_component(&control.styles, "password_parent", view)
+    }
+
+    fn number_input(
+        &self,
+        control: ControlRenderData<Self, NumberInputData>,
+        value_getter: Signal<String>,
+        value_setter: SignalSetter<String>,
+        validation_state: Signal<ValidationState>,
+    ) -> AnyView {
+        let input_mode = if control.data.decimal {
+            "decimal"
+        } else {
+            "numeric"
+        };
+        let view = view! {
+            <div>
+                <label for=control.data.name.clone() class="form_label">
+                    {control.data.label}
+                </label>
+                <span class="form_error">{move || validation_state.get().take_msg()}</span>
+            </div>
+            // the value is only set on change, so the text can be
+            // reformatted without moving the caret while typing
+            <input
+                type="text"
+                inputmode=input_mode
+                id=control.data.name.clone()
+                name=control.data.name.clone()
+                placeholder=control.data.placeholder
+                class="form_input form_number_input"
+                class=("form_input_invalid", move || validation_state.get().is_err())
+                prop:value=move || value_getter.get()
+                on:change:target=move |ev| {
+                    value_setter.set(ev.target().value());
+                }
+            />
+        }
+        .into_any();
+
+        self.common_component(&control.styles, "number_input_parent", view)
+    }
+
     fn slider(
         &self,
         control: ControlRenderData<Self, SliderData>,
@@ -488,4 +1020,955 @@ impl FormStyle for GridFormStyle {
 
         self.common_component(&control.styles, "slider_parent", view)
     }
+
+    fn file_input(
+        &self,
+        control: ControlRenderData<Self, FileInputData>,
+        value_getter: Signal<Vec<UploadedFile>>,
+        value_setter: SignalSetter<Vec<UploadedFile>>,
+        validation_state: Signal<ValidationState>,
+    ) -> AnyView {
+        // the browser does not let us set the files of an input, so the
+        // input is only cleared when the value is emptied (ex. on reset)
+        let input_ref = NodeRef::<leptos::html::Input>::new();
+        Effect::new(move |_| {
+            if value_getter.with(Vec::is_empty) {
+                if let Some(input) = input_ref.get() {
+                    input.set_value("");
+                }
+            }
+        });
+
+        let view = view! {
+            <div>
+                <label for=control.data.name.clone() class="form_label">
+                    {control.data.label.clone()}
+                </label>
+                <span class="form_error">{move || validation_state.get().take_msg()}</span>
+            </div>
+            <input
+                type="file"
+                node_ref=input_ref
+                id=control.data.name.clone()
+                name=control.data.name.clone()
+                multiple=control.data.multiple
+                accept=control.data.accept.clone()
+                class="form_input"
+                class=("form_input_invalid", move || validation_state.get().is_err())
+                on:change:target=move |ev| {
+                    let files = ev
+                        .target()
+                        .files()
+                        .map(|files| UploadedFile::from_file_list(&files))
+                        .unwrap_or_default();
+                    value_setter.set(files);
+                }
+            />
+            <ul class="form_file_list">
+                {move || {
+                    value_getter
+                        .get()
+                        .into_iter()
+                        .map(|file| view! { <li>{file.name}</li> })
+                        .collect_view()
+                }}
+            </ul>
+        }
+        .into_any();
+
+        self.common_component(&control.styles, "file_input_parent", view)
+    }
+
+    fn date_input(
+        &self,
+        control: ControlRenderData<Self, DateInputData>,
+        value_getter: Signal<String>,
+        value_setter: SignalSetter<String>,
+        validation_state: Signal<ValidationState>,
+    ) -> AnyView {
+        let view = view! {
+            <div>
+                <label for=control.data.name.clone() class="form_label">
+                    {control.data.label.clone()}
+                </label>
+                <span class="form_error">{move || validation_state.get().take_msg()}</span>
+            </div>
+            <input
+                type=control.data.kind.input_type()
+                id=control.data.name.clone()
+                name=control.data.name.clone()
+                min=control.data.min.clone()
+                max=control.data.max.clone()
+                step=control.data.step.clone()
+                class="form_input"
+                class=("form_input_invalid", move || validation_state.get().is_err())
+                prop:value=move || value_getter.get()
+                on:change:target=move |ev| value_setter.set(ev.target().value())
+            />
+        }
+        .into_any();
+
+        self.common_component(&control.styles, "date_input_parent", view)
+    }
+
+    fn multi_select(
+        &self,
+        control: ControlRenderData<Self, MultiSelectData>,
+        value_getter: Signal<Vec<String>>,
+        value_setter: SignalSetter<Vec<String>>,
+        validation_state: Signal<ValidationState>,
+    ) -> AnyView {
+        let options = control.data.options;
+        let options_view = move || {
+            options
+                .get()
+                .into_iter()
+                .map(|(display, value)| {
+                    let selected_value = value.clone();
+                    view! {
+                        <option
+                            value=value
+                            prop:selected=move || {
+                                value_getter.with(|values| values.contains(&selected_value))
+                            }
+                        >
+                            {display}
+                        </option>
+                    }
+                })
+                .collect_view()
+        };
+
+        let view = view! {
+            <div>
+                <label for=control.data.name.clone() class="form_label">
+                    {control.data.label}
+                </label>
+                <span class="form_error">{move || validation_state.get().take_msg()}</span>
+            </div>
+            <select
+                multiple
+                id=control.data.name.clone()
+                name=control.data.name
+                size=control.data.size
+                class="form_input"
+                class=("form_input_invalid", move || validation_state.get().is_err())
+                on:change:target=move |ev| {
+                    let selected = ev.target().selected_options();
+                    let values = (0..selected.length())
+                        .filter_map(|i| selected.item(i))
+                        .filter_map(|option| option.dyn_into::<HtmlOptionElement>().ok())
+                        .map(|option| option.value())
+                        .collect();
+                    value_setter.set(values);
+                }
+            >
+                {options_view}
+            </select>
+        }
+        .into_any();
+
+        self.common_component(&control.styles, "multi_select_parent", view)
+    }
+
+    fn checkbox_group(
+        &self,
+        control: ControlRenderData<Self, CheckboxGroupData>,
+        value_getter: Signal<Vec<String>>,
+        value_setter: SignalSetter<Vec<String>>,
+        validation_state: Signal<ValidationState>,
+    ) -> AnyView {
+        let inline = control.data.layout == OptionLayout::Inline;
+        let checkboxes_view = control
+            .data
+            .options
+            .iter()
+            .map(|(display, value)| {
+                let id = format!("{}_{}", control.data.name, value);
+                let checked_value = value.clone();
+                let toggled_value = value.clone();
+                view! {
+                    <span class="form_checkbox_group_option">
+                        <input
+                            type="checkbox"
+                            id=id.clone()
+                            name=control.data.name.clone()
+                            value=value.clone()
+                            prop:checked=move || {
+                                value_getter.with(|values| values.contains(&checked_value))
+                            }
+                            on:input=move |ev| {
+                                let checked = event_target_checked(&ev);
+                                let mut values = value_getter.get_untracked();
+                                values.retain(|v| *v != toggled_value);
+                                if checked {
+                                    values.push(toggled_value.clone());
+                                }
+                                value_setter.set(values);
+                            }
+                        />
+                        <label for=id>{display.clone()}</label>
+                    </span>
+                }
+            })
+            .collect_view();
+
+        let view = view! {
+            <div>
+                <label for=control.data.name class="form_label">
+                    {control.data.label}
+                </label>
+                <span class="form_error">{move || validation_state.get().take_msg()}</span>
+            </div>
+            <div
+                class="form_input"
+                class=("form_checkbox_group_inline", inline)
+                class=("form_checkbox_group_stacked", !inline)
+                class:form_input_invalid=move || validation_state.get().is_err()
+            >
+                {checkboxes_view}
+            </div>
+        }
+        .into_any();
+
+        self.common_component(&control.styles, "checkbox_group_parent", view)
+    }
+
+    fn combobox(
+        &self,
+        control: ControlRenderData<Self, ComboboxData>,
+        value_getter: Signal<String>,
+        value_setter: SignalSetter<String>,
+        validation_state: Signal<ValidationState>,
+    ) -> AnyView {
+        let ComboboxData {
+            name,
+            label,
+            placeholder,
+            must_pick,
+            query,
+            suggestions,
+            loading,
+        } = control.data;
+        let list_id = format!("{}_suggestions", name);
+        let option_prefix = format!("{}_suggestion", name);
+        let option_id = move |i: usize| format!("{}_{}", option_prefix, i);
+        let list_option_id = option_id.clone();
+
+        let text = RwSignal::new(value_getter.get_untracked());
+        let open = RwSignal::new(false);
+        let highlighted = RwSignal::new(None::<usize>);
+        // the (value, display) of the last picked suggestion
+        let picked = StoredValue::new(None::<(String, String)>);
+
+        // show the new value when it is changed outside of the combobox
+        Effect::watch(
+            move || value_getter.get(),
+            move |value, _, _| {
+                let was_picked = picked.with_value(|p| p.as_ref().is_some_and(|(v, _)| v == value));
+                if !was_picked && *value != text.get_untracked() {
+                    text.set(value.clone());
+                }
+            },
+            false,
+        );
+
+        let pick = move |display: String, value: String| {
+            text.set(display.clone());
+            picked.set_value(Some((value.clone(), display)));
+            value_setter.set(value);
+            open.set(false);
+            highlighted.set(None);
+        };
+
+        let on_keydown = move |ev: KeyboardEvent| {
+            let len = suggestions.with_untracked(Vec::len);
+            match ev.key().as_str() {
+                "ArrowDown" if len > 0 => {
+                    ev.prevent_default();
+                    open.set(true);
+                    highlighted.update(|h| *h = Some(h.map_or(0, |h| (h + 1).min(len - 1))));
+                }
+                "ArrowUp" if len > 0 => {
+                    ev.prevent_default();
+                    highlighted.update(|h| *h = Some(h.map_or(0, |h| h.saturating_sub(1))));
+                }
+                "Enter" => {
+                    let choice = highlighted
+                        .get_untracked()
+                        .filter(|_| open.get_untracked())
+                        .and_then(|i| suggestions.with_untracked(|s| s.get(i).cloned()));
+                    if let Some((display, value)) = choice {
+                        // pick the suggestion instead of submitting the form
+                        ev.prevent_default();
+                        pick(display, value);
+                    }
+                }
+                "Escape" => {
+                    open.set(false);
+                    highlighted.set(None);
+                }
+                _ => {}
+            }
+        };
+
+        let on_focusout = move |_| {
+            open.set(false);
+            highlighted.set(None);
+            if !must_pick {
+                return;
+            }
+            // only keep text that was picked from the suggestions
+            let current = text.get_untracked();
+            if current.is_empty() {
+                picked.set_value(None);
+                value_setter.set(String::new());
+                return;
+            }
+            let value = value_getter.get_untracked();
+            let display = picked
+                .with_value(|p| p.clone())
+                .filter(|(v, _)| *v == value)
+                .map(|(_, d)| d)
+                .unwrap_or(value);
+            if current != display {
+                text.set(display);
+            }
+        };
+
+        let suggestions_view = move || {
+            suggestions
+                .get()
+                .into_iter()
+                .enumerate()
+                .map(|(i, (display, value))| {
+                    let shown = display.clone();
+                    let selected_value = value.clone();
+                    view! {
+                        <li
+                            id=list_option_id(i)
+                            role="option"
+                            class="form_combobox_option"
+                            class=("form_combobox_option_active", move || highlighted.get() == Some(i))
+                            aria-selected=move || {
+                                value_getter.with(|v| *v == selected_value).to_string()
+                            }
+                            on:mousedown=move |ev| {
+                                // keep the focus on the input
+                                ev.prevent_default();
+                                pick(display.clone(), value.clone());
+                            }
+                        >
+                            {shown}
+                        </li>
+                    }
+                })
+                .collect_view()
+        };
+
+        let view = view! {
+            <div>
+                <label for=name.clone() class="form_label">
+                    {label}
+                </label>
+                <span class="form_error">{move || validation_state.get().take_msg()}</span>
+            </div>
+            <div class="form_combobox">
+                <input type="hidden" name=name.clone() prop:value=move || value_getter.get()/>
+                <input
+                    type="text"
+                    id=name.clone()
+                    role="combobox"
+                    autocomplete="off"
+                    aria-autocomplete="list"
+                    aria-controls=list_id.clone()
+                    aria-expanded=move || open.get().to_string()
+                    aria-activedescendant=move || highlighted.get().map(&option_id)
+                    placeholder=placeholder
+                    class="form_input"
+                    class=("form_input_invalid", move || validation_state.get().is_err())
+                    prop:value=move || text.get()
+                    on:input:target=move |ev| {
+                        let value = ev.target().value();
+                        text.set(value.clone());
+                        query.set(value.clone());
+                        open.set(true);
+                        highlighted.set(None);
+                        if !must_pick {
+                            value_setter.set(value);
+                        }
+                    }
+                    on:keydown=on_keydown
+                    on:focusout=on_focusout
+                />
+                <ul
+                    id=list_id
+                    role="listbox"
+                    class="form_combobox_list"
+                    hidden=move || !open.get() || suggestions.with(Vec::is_empty)
+                >
+                    {suggestions_view}
+                </ul>
+                <Show when=move || loading.get()>
+                    <span class="form_combobox_loading">"Loading..."</span>
+                </Show>
+            </div>
+        }
+        .into_any();
+
+        self.common_component(&control.styles, "combobox_parent", view)
+    }
+
+    fn tags_input(
+        &self,
+        control: ControlRenderData<Self, TagsInputData>,
+        value_getter: Signal<Vec<String>>,
+        value_setter: SignalSetter<Vec<String>>,
+        validation_state: Signal<ValidationState>,
+    ) -> AnyView {
+        let name = control.data.name.clone();
+        let label = control.data.label.clone();
+        let placeholder = control.data.placeholder.clone();
+        let data = StoredValue::new(control.data);
+        let draft = RwSignal::new(String::new());
+        let entry_error = RwSignal::new(None::<String>);
+
+        let add_tags = move |text: &str| {
+            let mut tags = value_getter.get_untracked();
+            let rejected = data.with_value(|data| {
+                for tag in data.split_tags(text) {
+                    if let Err(e) = data.check_tag(tag, &tags) {
+                        return Some((tag.to_string(), e));
+                    }
+                    tags.push(tag.to_string());
+                }
+                None
+            });
+            match rejected {
+                // keep the rejected tag so it can be fixed
+                Some((tag, e)) => {
+                    draft.set(tag);
+                    entry_error.set(Some(e));
+                }
+                None => {
+                    draft.set(String::new());
+                    entry_error.set(None);
+                }
+            }
+            value_setter.set(tags);
+        };
+
+        let remove_tag = move |i: usize| {
+            let mut tags = value_getter.get_untracked();
+            if i < tags.len() {
+                tags.remove(i);
+                value_setter.set(tags);
+            }
+        };
+
+        let on_keydown = move |ev: KeyboardEvent| {
+            let key = ev.key();
+            let is_separator = data.with_value(|data| {
+                let mut chars = key.chars();
+                matches!((chars.next(), chars.next()), (Some(c), None) if data.separators.contains(&c))
+            });
+            if key == "Enter" || is_separator {
+                let text = draft.get_untracked();
+                // an empty enter still submits the form
+                if text.trim().is_empty() && !is_separator {
+                    return;
+                }
+                ev.prevent_default();
+                add_tags(&text);
+            } else if key == "Backspace" && draft.with_untracked(String::is_empty) {
+                let len = value_getter.with_untracked(Vec::len);
+                if len > 0 {
+                    remove_tag(len - 1);
+                }
+            }
+        };
+
+        let chips_view = move || {
+            value_getter
+                .get()
+                .into_iter()
+                .enumerate()
+                .map(|(i, tag)| {
+                    view! {
+                        <span class="form_tag">
+                            {tag.clone()}
+                            <button
+                                type="button"
+                                class="form_tag_remove"
+                                aria-label=format!("Remove {}", tag)
+                                on:click=move |_| remove_tag(i)
+                            >
+                                "×"
+                            </button>
+                            <input type="hidden" name=data.with_value(|d| d.name.clone()) value=tag/>
+                        </span>
+                    }
+                })
+                .collect_view()
+        };
+
+        let view = view! {
+            <div>
+                <label for=name.clone() class="form_label">
+                    {label}
+                </label>
+                <span class="form_error">
+                    {move || entry_error.get().or_else(|| validation_state.get().take_msg())}
+                </span>
+            </div>
+            <div
+                class="form_input form_tags"
+                class=("form_input_invalid", move || validation_state.get().is_err())
+            >
+                {chips_view}
+                <input
+                    type="text"
+                    id=name
+                    class="form_tags_input"
+                    placeholder=placeholder
+                    prop:value=move || draft.get()
+                    on:input:target=move |ev| {
+                        let text = ev.target().value();
+                        let has_separator = data.with_value(|data| text.contains(&data.separators[..]));
+                        if has_separator {
+                            add_tags(&text);
+                        } else {
+                            draft.set(text);
+                            entry_error.set(None);
+                        }
+                    }
+                    on:keydown=on_keydown
+                    on:focusout=move |_| {
+                        let text = draft.get_untracked();
+                        if !text.trim().is_empty() {
+                            add_tags(&text);
+                        }
+                    }
+                />
+            </div>
+        }
+        .into_any();
+
+        self.common_component(&control.styles, "tags_input_parent", view)
+    }
+
+    fn switch(
+        &self,
+        control: ControlRenderData<Self, SwitchData>,
+        value_getter: Signal<bool>,
+        value_setter: SignalSetter<bool>,
+        validation_state: Signal<ValidationState>,
+    ) -> AnyView {
+        let SwitchData {
+            name,
+            label,
+            on_label,
+            off_label,
+            confirm,
+        } = control.data;
+
+        let on_click = move |_| {
+            if let Some(ref message) = confirm {
+                if !window().confirm_with_message(message).unwrap_or(false) {
+                    return;
+                }
+            }
+            value_setter.set(!value_getter.get_untracked());
+        };
+
+        let state_label = move || {
+            if value_getter.get() {
+                on_label.clone()
+            } else {
+                off_label.clone()
+            }
+        };
+
+        let view = view! {
+            <div>
+                <label for=name.clone() class="form_label">
+                    {label}
+                </label>
+                <span class="form_error">{move || validation_state.get().take_msg()}</span>
+            </div>
+            <div class="form_switch_row">
+                <button
+                    type="button"
+                    role="switch"
+                    id=name.clone()
+                    class="form_switch"
+                    class=("form_switch_on", move || value_getter.get())
+                    class=("form_input_invalid", move || validation_state.get().is_err())
+                    aria-checked=move || value_getter.get().to_string()
+                    on:click=on_click
+                >
+                    <span class="form_switch_thumb"></span>
+                </button>
+                <span class="form_switch_state">{state_label}</span>
+                <Show when=move || value_getter.get()>
+                    <input type="hidden" name=name.clone() value="checked"/>
+                </Show>
+            </div>
+        }
+        .into_any();
+
+        self.common_component(&control.styles, "switch_parent", view)
+    }
+
+    fn range_slider(
+        &self,
+        control: ControlRenderData<Self, RangeSliderData>,
+        value_getter: Signal<(String, String)>,
+        value_setter: SignalSetter<(String, String)>,
+        validation_state: Signal<ValidationState>,
+    ) -> AnyView {
+        // keeps the handle from passing the other handle
+        let clamp = |value: String, bound: &str, keep_below: bool| -> String {
+            match (value.parse::<f64>(), bound.parse::<f64>()) {
+                (Ok(v), Ok(b)) if (keep_below && v > b) || (!keep_below && v < b) => {
+                    bound.to_string()
+                }
+                _ => value,
+            }
+        };
+
+        let view = view! {
+            <div>
+                <label for=format!("{}_low", control.data.name) class="form_label">
+                    {control.data.label}
+                </label>
+                <span class="form_error">{move || validation_state.get().take_msg()}</span>
+            </div>
+            <div class="form_range_slider">
+                <input
+                    type="range"
+                    id=format!("{}_low", control.data.name)
+                    name=format!("{}_low", control.data.name)
+                    min=control.data.min
+                    max=control.data.max
+                    step=control.data.step
+                    class="form_input"
+                    class=("form_input_invalid", move || validation_state.get().is_err())
+                    prop:value=move || value_getter.get().0
+                    on:input:target=move |ev| {
+                        let (_, high) = value_getter.get_untracked();
+                        let low = clamp(ev.target().value(), &high, true);
+                        // move the handle back if it was clamped
+                        ev.target().set_value(&low);
+                        value_setter.set((low, high));
+                    }
+                />
+                <input
+                    type="range"
+                    id=format!("{}_high", control.data.name)
+                    name=format!("{}_high", control.data.name)
+                    min=control.data.min
+                    max=control.data.max
+                    step=control.data.step
+                    class="form_input"
+                    class=("form_input_invalid", move || validation_state.get().is_err())
+                    prop:value=move || value_getter.get().1
+                    on:input:target=move |ev| {
+                        let (low, _) = value_getter.get_untracked();
+                        let high = clamp(ev.target().value(), &low, false);
+                        ev.target().set_value(&high);
+                        value_setter.set((low, high));
+                    }
+                />
+                <span class="form_range_slider_values">
+                    {move || {
+                        let (low, high) = value_getter.get();
+                        format!("{} – {}", low, high)
+                    }}
+                </span>
+            </div>
+        }
+        .into_any();
+
+        self.common_component(&control.styles, "range_slider_parent", view)
+    }
+
+    fn otp_input(
+        &self,
+        control: ControlRenderData<Self, OtpInputData>,
+        value_getter: Signal<String>,
+        value_setter: SignalSetter<String>,
+        validation_state: Signal<ValidationState>,
+    ) -> AnyView {
+        let length = control.data.length;
+        let charset = control.data.charset;
+        let auto_submit = control.data.auto_submit;
+        let name = control.data.name.clone();
+
+        let split_code = move |code: &str| {
+            let mut boxes = code
+                .chars()
+                .take(length)
+                .map(String::from)
+                .collect::<Vec<_>>();
+            boxes.resize(length, String::new());
+            boxes
+        };
+        let boxes = RwSignal::new(split_code(&value_getter.get_untracked()));
+        Effect::watch(
+            move || value_getter.get(),
+            move |code, _, _| {
+                if boxes.with_untracked(|boxes| boxes.concat()) != *code {
+                    boxes.set(split_code(code));
+                }
+            },
+            false,
+        );
+
+        let refs = StoredValue::new(
+            (0..length)
+                .map(|_| NodeRef::<leptos::html::Input>::new())
+                .collect::<Vec<_>>(),
+        );
+        let focus = move |index: usize| {
+            if let Some(input) = refs.with_value(|refs| refs.get(index).and_then(|r| r.get())) {
+                let _ = input.focus();
+                input.select();
+            }
+        };
+        let commit = move || {
+            let code = boxes.with_untracked(|boxes| boxes.concat());
+            let complete = code.chars().count() == length;
+            value_setter.set(code);
+            if auto_submit && complete {
+                let form = refs
+                    .with_value(|refs| refs.first().and_then(|r| r.get()))
+                    .and_then(|input| input.form());
+                if let Some(form) = form {
+                    let _ = form.request_submit();
+                }
+            }
+        };
+        // spreads the text over the boxes from `start`, giving the next box
+        // to focus
+        let fill = move |start: usize, text: &str| {
+            let chars = text.chars().filter(|c| charset.accepts(*c));
+            let mut next = start;
+            boxes.update(|boxes| {
+                for (index, c) in (start..length).zip(chars) {
+                    boxes[index] = c.to_string();
+                    next = index + 1;
+                }
+            });
+            next.min(length.saturating_sub(1))
+        };
+
+        let inputs = (0..length)
+            .map(|index| {
+                let node_ref = refs.with_value(|refs| refs[index]);
+                view! {
+                    <input
+                        node_ref=node_ref
+                        type="text"
+                        id=format!("{}_{}", name, index)
+                        inputmode=charset.input_mode()
+                        autocomplete=if index == 0 { "one-time-code" } else { "off" }
+                        aria-label=format!("Character {} of {}", index + 1, length)
+                        class="form_input form_otp_box"
+                        class=("form_input_invalid", move || validation_state.get().is_err())
+                        prop:value=move || boxes.with(|boxes| boxes[index].clone())
+                        on:focus:target=move |ev| ev.target().select()
+                        on:input:target=move |ev| {
+                            let target = ev.target();
+                            let mut text = target.value();
+                            let old = boxes.with_untracked(|boxes| boxes[index].clone());
+                            // typing into a filled box adds to the old character
+                            if text.chars().count() > 1 && !old.is_empty() {
+                                if let Some(at) = text.find(&old) {
+                                    text.replace_range(at..at + old.len(), "");
+                                }
+                            }
+
+                            if text.is_empty() {
+                                boxes.update(|boxes| boxes[index].clear());
+                                commit();
+                                return;
+                            }
+                            let next = if text.chars().any(|c| charset.accepts(c)) {
+                                Some(fill(index, &text))
+                            } else {
+                                None
+                            };
+                            target.set_value(&boxes.with_untracked(|boxes| boxes[index].clone()));
+                            if let Some(next) = next {
+                                focus(next);
+                                commit();
+                            }
+                        }
+                        on:paste=move |ev| {
+                            ev.prevent_default();
+                            let text = ev
+                                .clipboard_data()
+                                .and_then(|data| data.get_data("text").ok())
+                                .unwrap_or_default();
+                            focus(fill(index, &text));
+                            commit();
+                        }
+                        on:keydown=move |ev| match ev.key().as_str() {
+                            "Backspace" if index > 0
+                                && boxes.with_untracked(|boxes| boxes[index].is_empty()) => {
+                                ev.prevent_default();
+                                boxes.update(|boxes| boxes[index - 1].clear());
+                                focus(index - 1);
+                                commit();
+                            }
+                            "ArrowLeft" if index > 0 => {
+                                ev.prevent_default();
+                                focus(index - 1);
+                            }
+                            "ArrowRight" if index + 1 < length => {
+                                ev.prevent_default();
+                                focus(index + 1);
+                            }
+                            _ => {}
+                        }
+                    />
+                }
+            })
+            .collect_view();
+
+        let view = view! {
+            <div>
+                <label for=format!("{}_0", name) class="form_label">
+                    {control.data.label}
+                </label>
+                <span class="form_error">{move || validation_state.get().take_msg()}</span>
+            </div>
+            <div class="form_otp">{inputs}</div>
+            <input type="hidden" name=name prop:value=move || value_getter.get() />
+        }
+        .into_any();
+
+        self.common_component(&control.styles, "otp_input_parent", view)
+    }
+}
+
+/// Re-applies the mask to the input's text, keeping the caret after the
+/// same character.
+fn format_masked(target: &HtmlInputElement, mask: &str) {
+    let text = target.value();
+    // the browser gives the caret as an offset in UTF-16 code units
+    let before = match target.selection_start().ok().flatten() {
+        Some(caret) => &text[..utf16_to_byte_offset(&text, caret as usize)],
+        None => text.as_str(),
+    };
+
+    let raw = strip_mask(mask, &text);
+    let formatted = apply_mask(mask, &raw);
+    if formatted != text {
+        target.set_value(&formatted);
+        let caret = masked_caret(mask, strip_mask(mask, before).chars().count());
+        let caret = chars_to_utf16_offset(&formatted, caret) as u32;
+        let _ = target.set_selection_range(caret, caret);
+    }
+}
+
+/// Gets the byte offset in `text` of an offset in UTF-16 code units.
+///
+/// An offset past the end of `text` gives the end of `text`.
+fn utf16_to_byte_offset(text: &str, utf16_offset: usize) -> usize {
+    let mut units = 0;
+    for (i, c) in text.char_indices() {
+        if units >= utf16_offset {
+            return i;
+        }
+        units += c.len_utf16();
+    }
+    text.len()
+}
+
+/// Gets the offset in UTF-16 code units after the first `chars` characters
+/// of `text`.
+fn chars_to_utf16_offset(text: &str, chars: usize) -> usize {
+    text.chars().take(chars).map(char::len_utf16).sum()
+}
+
+/// The html value of the "other" option in selects and radio buttons.
+const OTHER_OPTION: &str = "__other__";
+
+/// Gets whether the "other" option is picked and its text, which follow
+/// the value when it changes.
+///
+/// These are kept separately from the value, as the value isn't updated
+/// while the "other" text is empty.
+fn other_state(value_getter: Signal<String>) -> (RwSignal<bool>, RwSignal<String>) {
+    let initial = value_getter.get_untracked();
+    let other_picked = RwSignal::new(other_text(&initial).is_some());
+    let other_input = RwSignal::new(other_text(&initial).unwrap_or_default().to_string());
+    Effect::watch(
+        move || value_getter.get(),
+        move |value, _, _| match other_text(value) {
+            Some(text) => {
+                other_picked.set(true);
+                if other_input.with_untracked(|input| input != text) {
+                    other_input.set(text.to_string());
+                }
+            }
+            None => other_picked.set(false),
+        },
+        false,
+    );
+    (other_picked, other_input)
+}
+
+/// Renders the text input for the "other" option, which is shown when it
+/// is picked.
+fn other_input_view(
+    name: String,
+    label: String,
+    other_picked: RwSignal<bool>,
+    other_input: RwSignal<String>,
+    value_setter: SignalSetter<String>,
+) -> impl IntoView {
+    view! {
+        <Show when=move || other_picked.get()>
+            <input
+                type="text"
+                name=name.clone()
+                placeholder=label.clone()
+                aria-label=label.clone()
+                class="form_input form_other_input"
+                prop:value=move || other_input.get()
+                on:input:target=move |ev| {
+                    let text = ev.target().value();
+                    other_input.set(text.clone());
+                    value_setter.set(other_value(&text));
+                }
+            />
+        </Show>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn utf16_offsets_convert_to_bytes() {
+        assert_eq!(utf16_to_byte_offset("abc", 2), 2);
+        // 'é' is 2 bytes and 1 UTF-16 unit
+        assert_eq!(utf16_to_byte_offset("éa", 1), 2);
+        // '😀' is 4 bytes and 2 UTF-16 units
+        assert_eq!(utf16_to_byte_offset("😀a", 2), 4);
+        assert_eq!(utf16_to_byte_offset("😀a", 3), 5);
+        assert_eq!(utf16_to_byte_offset("ab", 10), 2);
+    }
+
+    #[test]
+    fn chars_convert_to_utf16_offsets() {
+        assert_eq!(chars_to_utf16_offset("abc", 2), 2);
+        assert_eq!(chars_to_utf16_offset("😀a", 1), 2);
+        assert_eq!(chars_to_utf16_offset("😀a", 2), 3);
+        assert_eq!(chars_to_utf16_offset("ab", 10), 2);
+    }
 }
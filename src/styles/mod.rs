@@ -1,16 +1,37 @@
 mod grid_form;
 
-use crate::controls::{
-    button::ButtonData, checkbox::CheckboxData, heading::HeadingData, hidden::HiddenData,
-    output::OutputData, radio_buttons::RadioButtonsData, select::SelectData, slider::SliderData,
-    spacer::SpacerData, stepper::StepperData, submit::SubmitData, text_area::TextAreaData,
-    text_input::TextInputData, ControlRenderData, ValidationState,
+use crate::{
+    controls::{
+        button::ButtonData,
+        checkbox::CheckboxData,
+        checkbox_group::CheckboxGroupData,
+        combobox::ComboboxData,
+        date_input::DateInputData,
+        file_input::{FileInputData, UploadedFile},
+        heading::HeadingData,
+        hidden::HiddenData,
+        multi_select::MultiSelectData,
+        number_input::NumberInputData,
+        otp_input::OtpInputData,
+        output::OutputData,
+        password::PasswordData,
+        radio_buttons::RadioButtonsData,
+        range_slider::RangeSliderData,
+        select::SelectData,
+        slider::SliderData,
+        spacer::SpacerData,
+        stepper::StepperData,
+        submit::SubmitData,
+        switch::SwitchData,
+        tags_input::TagsInputData,
+        text_area::TextAreaData,
+        text_input::TextInputData,
+        ControlRenderData, ValidationState,
+    },
+    form::FormStatus,
 };
 pub use grid_form::{GFStyleAttr, GridFormStyle};
-use leptos::{
-    prelude::{AnyView, Signal},
-    reactive::wrappers::write::SignalSetter,
-};
+use leptos::{prelude::*, reactive::wrappers::write::SignalSetter, view};
 
 /// Defines a way to style a form.
 ///
@@ -62,13 +83,33 @@ pub trait FormStyle: Send + Sync + 'static {
         value_getter: Option<Signal<String>>,
     ) -> AnyView;
 
+    /// Renders the form level error, if there is one.
+    ///
+    /// This is rendered once, after all the form's controls, for forms
+    /// that are sent to a server action. The error is the `status`'s
+    /// [`err_msg`](FormStatus::err_msg).
+    ///
+    /// By default, this renders the error in a `p` element.
+    fn form_error(&self, status: Signal<FormStatus>) -> AnyView {
+        view! {
+            <Show when=move || status.with(FormStatus::is_failed)>
+                <p role="alert">{move || status.with(|s| s.err_msg().cloned())}</p>
+            </Show>
+        }
+        .into_any()
+    }
+
     /// Renders a submit button.
     ///
+    /// The `status` of the form is given so that the button can be disabled
+    /// and show progress while the form is being submitted.
+    ///
     /// See [`SubmitData`].
     fn submit(
         &self,
         control: ControlRenderData<Self, SubmitData>,
         value_getter: Option<Signal<String>>,
+        status: Signal<FormStatus>,
     ) -> AnyView;
 
     /// Renders a button.
@@ -100,6 +141,9 @@ pub trait FormStyle: Send + Sync + 'static {
 
     /// Renders a text input control.
     ///
+    /// If the control has a `mask`, the style may show the text formatted
+    /// with it; the control strips the mask from whatever is set.
+    ///
     /// See [`TextInputData`].
     fn text_input(
         &self,
@@ -133,6 +177,10 @@ pub trait FormStyle: Send + Sync + 'static {
 
     /// Renders a select (or dropdown) control.
     ///
+    /// Options with a group should be shown under that group, which
+    /// [`SelectOption::grouped`](crate::controls::select::SelectOption::grouped)
+    /// helps with.
+    ///
     /// See [`SelectData`].
     fn select(
         &self,
@@ -142,6 +190,23 @@ pub trait FormStyle: Send + Sync + 'static {
         validation_state: Signal<ValidationState>,
     ) -> AnyView;
 
+    /// Renders a searchable select, for long lists of options.
+    ///
+    /// This gets the same data as [`select`](Self::select), and should let
+    /// the user filter the options by typing. Styles should only render the
+    /// visible options, so that lists with thousands of options stay fast.
+    ///
+    /// By default, this renders a plain [`select`](Self::select).
+    fn searchable_select(
+        &self,
+        control: ControlRenderData<Self, SelectData>,
+        value_getter: Signal<String>,
+        value_setter: SignalSetter<String>,
+        validation_state: Signal<ValidationState>,
+    ) -> AnyView {
+        self.select(control, value_getter, value_setter, validation_state)
+    }
+
     /// Renders a checkbox control.
     ///
     /// See [`CheckboxData`].
@@ -150,6 +215,7 @@ pub trait FormStyle: Send + Sync + 'static {
         control: ControlRenderData<Self, CheckboxData>,
         value_getter: Signal<bool>,
         value_setter: SignalSetter<bool>,
+        validation_state: Signal<ValidationState>,
     ) -> AnyView;
 
     /// Renders a stepper control.
@@ -163,6 +229,62 @@ pub trait FormStyle: Send + Sync + 'static {
         validation_state: Signal<ValidationState>,
     ) -> AnyView;
 
+    /// Renders a password control.
+    ///
+    /// The style should let the user reveal the password, and show its
+    /// strength when [`PasswordData::strength`] is set.
+    ///
+    /// By default, this renders a [`text_input`](Self::text_input) with the
+    /// "password" type.
+    ///
+    /// See [`PasswordData`].
+    fn password(
+        &self,
+        control: ControlRenderData<Self, PasswordData>,
+        value_getter: Signal<String>,
+        value_setter: SignalSetter<String>,
+        validation_state: Signal<ValidationState>,
+    ) -> AnyView {
+        let data = TextInputData {
+            name: control.data.name,
+            label: control.data.label,
+            placeholder: control.data.placeholder,
+            input_type: "password",
+            ..Default::default()
+        };
+        let control = ControlRenderData {
+            styles: control.styles,
+            data,
+        };
+        self.text_input(control, value_getter, value_setter, validation_state)
+    }
+
+    /// Renders a number input control.
+    ///
+    /// By default, this renders a [`text_input`](Self::text_input).
+    ///
+    /// See [`NumberInputData`].
+    fn number_input(
+        &self,
+        control: ControlRenderData<Self, NumberInputData>,
+        value_getter: Signal<String>,
+        value_setter: SignalSetter<String>,
+        validation_state: Signal<ValidationState>,
+    ) -> AnyView {
+        let data = TextInputData {
+            name: control.data.name,
+            label: control.data.label,
+            placeholder: control.data.placeholder,
+            input_type: "text",
+            ..Default::default()
+        };
+        let control = ControlRenderData {
+            styles: control.styles,
+            data,
+        };
+        self.text_input(control, value_getter, value_setter, validation_state)
+    }
+
     /// Renders a slider control.
     ///
     /// See [`SliderData`].
@@ -173,4 +295,394 @@ pub trait FormStyle: Send + Sync + 'static {
         value_setter: SignalSetter<String>,
         validation_state: Signal<ValidationState>,
     ) -> AnyView;
+
+    /// Renders a file input control.
+    ///
+    /// By default, this renders a plain html file input.
+    ///
+    /// See [`FileInputData`].
+    fn file_input(
+        &self,
+        control: ControlRenderData<Self, FileInputData>,
+        value_getter: Signal<Vec<UploadedFile>>,
+        value_setter: SignalSetter<Vec<UploadedFile>>,
+        validation_state: Signal<ValidationState>,
+    ) -> AnyView {
+        // the browser does not let us set the files of an input, so the
+        // input is only cleared when the value is emptied
+        let input_ref = NodeRef::<leptos::html::Input>::new();
+        Effect::new(move |_| {
+            if value_getter.with(Vec::is_empty) {
+                if let Some(input) = input_ref.get() {
+                    input.set_value("");
+                }
+            }
+        });
+
+        let view = view! {
+            <label for=control.data.name.clone()>{control.data.label.clone()}</label>
+            <input
+                type="file"
+                node_ref=input_ref
+                id=control.data.name.clone()
+                name=control.data.name.clone()
+                multiple=control.data.multiple
+                accept=control.data.accept.clone()
+                on:change:target=move |ev| {
+                    let files = ev
+                        .target()
+                        .files()
+                        .map(|files| UploadedFile::from_file_list(&files))
+                        .unwrap_or_default();
+                    value_setter.set(files);
+                }
+            />
+            <span>{move || validation_state.get().take_msg()}</span>
+        }
+        .into_any();
+        self.custom_component(&control.styles, view)
+    }
+
+    /// Renders a date input control.
+    ///
+    /// This is used for date, time, datetime and month inputs.
+    ///
+    /// By default, this renders a [`text_input`](Self::text_input) with the
+    /// kind's html input type.
+    ///
+    /// See [`DateInputData`].
+    fn date_input(
+        &self,
+        control: ControlRenderData<Self, DateInputData>,
+        value_getter: Signal<String>,
+        value_setter: SignalSetter<String>,
+        validation_state: Signal<ValidationState>,
+    ) -> AnyView {
+        let data = TextInputData {
+            name: control.data.name,
+            label: control.data.label,
+            input_type: control.data.kind.input_type(),
+            ..Default::default()
+        };
+        let control = ControlRenderData {
+            styles: control.styles,
+            data,
+        };
+        self.text_input(control, value_getter, value_setter, validation_state)
+    }
+
+    /// Renders a multi select control.
+    ///
+    /// By default, this renders a plain html select that allows picking
+    /// several options.
+    ///
+    /// See [`MultiSelectData`].
+    fn multi_select(
+        &self,
+        control: ControlRenderData<Self, MultiSelectData>,
+        value_getter: Signal<Vec<String>>,
+        value_setter: SignalSetter<Vec<String>>,
+        validation_state: Signal<ValidationState>,
+    ) -> AnyView {
+        let options = control.data.options;
+        let view = view! {
+            <label for=control.data.name.clone()>{control.data.label.clone()}</label>
+            <select
+                multiple
+                id=control.data.name.clone()
+                name=control.data.name.clone()
+                size=control.data.size
+                on:change:target=move |ev| {
+                    let picked = ev.target().selected_options();
+                    let values = (0..picked.length())
+                        .filter_map(|i| picked.item(i))
+                        .filter_map(|option| option.get_attribute("value"))
+                        .collect();
+                    value_setter.set(values);
+                }
+            >
+                {move || {
+                    options
+                        .get()
+                        .into_iter()
+                        .map(|(display, value)| {
+                            let picked_value = value.clone();
+                            let selected = move || {
+                                value_getter.with(|picked| picked.contains(&picked_value))
+                            };
+                            view! {
+                                <option value=value selected=selected>
+                                    {display}
+                                </option>
+                            }
+                        })
+                        .collect_view()
+                }}
+            </select>
+            <span>{move || validation_state.get().take_msg()}</span>
+        }
+        .into_any();
+        self.custom_component(&control.styles, view)
+    }
+
+    /// Renders a group of checkboxes.
+    ///
+    /// By default, this renders a [`checkbox`](Self::checkbox) for each
+    /// option, with the validation error shown on the first one.
+    ///
+    /// See [`CheckboxGroupData`].
+    fn checkbox_group(
+        &self,
+        control: ControlRenderData<Self, CheckboxGroupData>,
+        value_getter: Signal<Vec<String>>,
+        value_setter: SignalSetter<Vec<String>>,
+        validation_state: Signal<ValidationState>,
+    ) -> AnyView {
+        let name = control.data.name;
+        let checkboxes = control
+            .data
+            .options
+            .into_iter()
+            .enumerate()
+            .map(|(i, (display, value))| {
+                let data = CheckboxData {
+                    name: format!("{}_{}", name, value),
+                    label: Some(display),
+                };
+                let checked_value = value.clone();
+                let getter = Signal::derive(move || {
+                    value_getter.with(|values| values.contains(&checked_value))
+                });
+                let setter = SignalSetter::map(move |checked| {
+                    let mut values = value_getter.get_untracked();
+                    values.retain(|v| *v != value);
+                    if checked {
+                        values.push(value.clone());
+                    }
+                    value_setter.set(values);
+                });
+                let validation_state = match i {
+                    0 => validation_state,
+                    _ => Signal::stored(ValidationState::Passed),
+                };
+                let control = ControlRenderData {
+                    styles: control.styles.clone(),
+                    data,
+                };
+                self.checkbox(control, getter, setter, validation_state)
+            })
+            .collect::<Vec<_>>();
+        view! {
+            <fieldset>
+                <legend>{control.data.label}</legend>
+                {checkboxes}
+            </fieldset>
+        }
+        .into_any()
+    }
+
+    /// Renders a combobox control.
+    ///
+    /// The style should set the control's `query` as the user types, and
+    /// show the `suggestions` for the user to pick from.
+    /// Unless `must_pick` is set, the typed text is the value. With it, the
+    /// control fails validation for a value that wasn't suggested.
+    ///
+    /// By default, this renders a text input that sets the query as the
+    /// user types, with the suggestions in a `<datalist>`.
+    ///
+    /// See [`ComboboxData`].
+    fn combobox(
+        &self,
+        control: ControlRenderData<Self, ComboboxData>,
+        value_getter: Signal<String>,
+        value_setter: SignalSetter<String>,
+        validation_state: Signal<ValidationState>,
+    ) -> AnyView {
+        let query = control.data.query;
+        let suggestions = control.data.suggestions;
+        let loading = control.data.loading;
+        let list_id = format!("{}_suggestions", control.data.name);
+
+        let view = view! {
+            <label for=control.data.name.clone()>{control.data.label.clone()}</label>
+            <input
+                type="text"
+                id=control.data.name.clone()
+                name=control.data.name.clone()
+                list=list_id.clone()
+                autocomplete="off"
+                placeholder=control.data.placeholder.clone()
+                prop:value=move || value_getter.get()
+                on:input:target=move |ev| {
+                    let value = ev.target().value();
+                    query.set(value.clone());
+                    value_setter.set(value);
+                }
+            />
+            <datalist id=list_id>
+                {move || {
+                    suggestions
+                        .get()
+                        .into_iter()
+                        .map(|(display, value)| view! { <option value=value>{display}</option> })
+                        .collect_view()
+                }}
+            </datalist>
+            <Show when=move || loading.get()>
+                <span>"Loading..."</span>
+            </Show>
+            <span>{move || validation_state.get().take_msg()}</span>
+        }
+        .into_any();
+        self.custom_component(&control.styles, view)
+    }
+
+    /// Renders a tags input control.
+    ///
+    /// The style should use [`TagsInputData::split_tags`] and
+    /// [`TagsInputData::check_tag`] when the user adds tags.
+    ///
+    /// By default, this renders a [`text_input`](Self::text_input) with the
+    /// tags joined by the first separator.
+    ///
+    /// See [`TagsInputData`].
+    fn tags_input(
+        &self,
+        control: ControlRenderData<Self, TagsInputData>,
+        value_getter: Signal<Vec<String>>,
+        value_setter: SignalSetter<Vec<String>>,
+        validation_state: Signal<ValidationState>,
+    ) -> AnyView {
+        let separator = control.data.separators.first().copied().unwrap_or(',');
+        let tags_data = control.data.clone();
+        let data = TextInputData {
+            name: control.data.name,
+            label: control.data.label,
+            placeholder: control.data.placeholder,
+            input_type: "text",
+            ..Default::default()
+        };
+        let control = ControlRenderData {
+            styles: control.styles,
+            data,
+        };
+        let joined = Signal::derive(move || value_getter.get().join(&format!("{} ", separator)));
+        let value_setter = SignalSetter::map(move |text: String| {
+            let mut tags = Vec::new();
+            for tag in tags_data.split_tags(&text) {
+                if tags_data.check_tag(tag, &tags).is_ok() {
+                    tags.push(tag.to_string());
+                }
+            }
+            value_setter.set(tags);
+        });
+        self.text_input(control, joined, value_setter, validation_state)
+    }
+
+    /// Renders a switch control.
+    ///
+    /// If the control has a `confirm` message, the style should confirm
+    /// with the user before toggling the switch.
+    ///
+    /// By default, this renders a [`checkbox`](Self::checkbox), without
+    /// confirming.
+    ///
+    /// See [`SwitchData`].
+    fn switch(
+        &self,
+        control: ControlRenderData<Self, SwitchData>,
+        value_getter: Signal<bool>,
+        value_setter: SignalSetter<bool>,
+        validation_state: Signal<ValidationState>,
+    ) -> AnyView {
+        let data = CheckboxData {
+            name: control.data.name,
+            label: control.data.label,
+        };
+        let control = ControlRenderData {
+            styles: control.styles,
+            data,
+        };
+        self.checkbox(control, value_getter, value_setter, validation_state)
+    }
+
+    /// Renders a range slider control.
+    ///
+    /// The value is the (low, high) pair. The style should keep the low
+    /// value at or below the high value.
+    ///
+    /// By default, this renders a [`slider`](Self::slider) for each of the
+    /// low and high values.
+    ///
+    /// See [`RangeSliderData`].
+    fn range_slider(
+        &self,
+        control: ControlRenderData<Self, RangeSliderData>,
+        value_getter: Signal<(String, String)>,
+        value_setter: SignalSetter<(String, String)>,
+        validation_state: Signal<ValidationState>,
+    ) -> AnyView {
+        let slider = |suffix: &str| ControlRenderData {
+            styles: control.styles.clone(),
+            data: SliderData {
+                name: format!("{}_{}", control.data.name, suffix),
+                label: control.data.label.clone(),
+                step: control.data.step,
+                min: control.data.min,
+                max: control.data.max,
+            },
+        };
+        let low = Signal::derive(move || value_getter.get().0);
+        let set_low = SignalSetter::map(move |low: String| {
+            let (_, high) = value_getter.get_untracked();
+            value_setter.set((low, high));
+        });
+        let high = Signal::derive(move || value_getter.get().1);
+        let set_high = SignalSetter::map(move |high: String| {
+            let (low, _) = value_getter.get_untracked();
+            value_setter.set((low, high));
+        });
+        let low_view = self.slider(slider("low"), low, set_low, validation_state);
+        let high_view = self.slider(
+            slider("high"),
+            high,
+            set_high,
+            Signal::stored(ValidationState::Passed),
+        );
+        view! {
+            {low_view}
+            {high_view}
+        }
+        .into_any()
+    }
+
+    /// Renders a one-time code input control.
+    ///
+    /// The style should submit the enclosing form when
+    /// [`OtpInputData::auto_submit`] is set and the code is complete.
+    ///
+    /// By default, this renders a [`text_input`](Self::text_input), without
+    /// submitting the form.
+    ///
+    /// See [`OtpInputData`].
+    fn otp_input(
+        &self,
+        control: ControlRenderData<Self, OtpInputData>,
+        value_getter: Signal<String>,
+        value_setter: SignalSetter<String>,
+        validation_state: Signal<ValidationState>,
+    ) -> AnyView {
+        let data = TextInputData {
+            name: control.data.name,
+            label: control.data.label,
+            input_type: "text",
+            ..Default::default()
+        };
+        let control = ControlRenderData {
+            styles: control.styles,
+            data,
+        };
+        self.text_input(control, value_getter, value_setter, validation_state)
+    }
 }
@@ -1,4 +1,7 @@
-use crate::{controls::ValidationFn, FormToolData};
+use crate::{
+    controls::{file_input::UploadedFile, ValidationFn},
+    FormToolData,
+};
 use std::fmt::Display;
 
 /// A function that validates a field.
@@ -79,6 +82,23 @@ impl<FD: FormToolData, T> ValidationBuilder<FD, Option<T>> {
     }
 }
 
+impl<FD: FormToolData> ValidationBuilder<FD, bool> {
+    /// Requires the field to be `true`.
+    ///
+    /// This is useful for checkboxes that must be checked, like accepting
+    /// the terms and conditions.
+    pub fn must_be_checked(mut self) -> Self {
+        self.functions.push(Box::new(move |name, value| {
+            if !*value {
+                Err(format!("{} must be checked", name))
+            } else {
+                Ok(())
+            }
+        }));
+        self
+    }
+}
+
 impl<FD: FormToolData> ValidationBuilder<FD, str> {
     /// Requires the field to not be empty.
     pub fn required(mut self) -> Self {
@@ -130,6 +150,97 @@ impl<FD: FormToolData> ValidationBuilder<FD, str> {
     }
 }
 
+impl<FD: FormToolData> ValidationBuilder<FD, [UploadedFile]> {
+    /// Requires at least one file to be picked.
+    pub fn required(mut self) -> Self {
+        self.functions.push(Box::new(move |name, value| {
+            if value.is_empty() {
+                Err(format!("{} is required", name))
+            } else {
+                Ok(())
+            }
+        }));
+        self
+    }
+
+    /// Requires no more than `max_files` files to be picked.
+    pub fn max_files(mut self, max_files: usize) -> Self {
+        self.functions.push(Box::new(move |name, value| {
+            if value.len() > max_files {
+                Err(format!("{} must have <= {} files", name, max_files))
+            } else {
+                Ok(())
+            }
+        }));
+        self
+    }
+
+    /// Requires every file to be at most `max_size` bytes.
+    pub fn max_file_size(mut self, max_size: u64) -> Self {
+        self.functions.push(Box::new(move |name, value| {
+            match value.iter().find(|file| file.size > max_size) {
+                Some(file) => Err(format!(
+                    "{} must be <= {} bytes, but {} is {} bytes",
+                    name, max_size, file.name, file.size
+                )),
+                None => Ok(()),
+            }
+        }));
+        self
+    }
+}
+
+impl<FD: FormToolData, C: ?Sized + 'static> ValidationBuilder<FD, C>
+where
+    for<'a> &'a C: IntoIterator,
+{
+    /// Requires the collection to have at least `min_items` items.
+    ///
+    /// This can be used with `min_items(1)` to require a selection.
+    pub fn min_items(mut self, min_items: usize) -> Self {
+        self.functions.push(Box::new(move |name, value| {
+            if value.into_iter().count() < min_items {
+                Err(format!("{} must have >= {} items", name, min_items))
+            } else {
+                Ok(())
+            }
+        }));
+        self
+    }
+
+    /// Requires the collection to have at most `max_items` items.
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.functions.push(Box::new(move |name, value| {
+            if value.into_iter().count() > max_items {
+                Err(format!("{} must have <= {} items", name, max_items))
+            } else {
+                Ok(())
+            }
+        }));
+        self
+    }
+
+    /// Requires every item in the collection to be different.
+    pub fn unique_items(mut self) -> Self
+    where
+        for<'a> <&'a C as IntoIterator>::Item: PartialEq,
+    {
+        self.functions.push(Box::new(move |name, value| {
+            let items: Vec<_> = value.into_iter().collect();
+            let has_duplicate = items
+                .iter()
+                .enumerate()
+                .any(|(i, item)| items[..i].contains(item));
+            if has_duplicate {
+                Err(format!("{} can't have duplicate items", name))
+            } else {
+                Ok(())
+            }
+        }));
+        self
+    }
+}
+
 impl<FD: FormToolData, T: PartialOrd<T> + Display + Send + Sync + 'static>
     ValidationBuilder<FD, T>
 {
@@ -158,6 +269,68 @@ impl<FD: FormToolData, T: PartialOrd<T> + Display + Send + Sync + 'static>
         }));
         self
     }
+
+    /// Requires the value to be strictly before (less than) `bound`.
+    ///
+    /// This is useful for dates and times.
+    pub fn before(mut self, bound: T) -> Self {
+        self.functions.push(Box::new(move |name, value| {
+            if value >= &bound {
+                Err(format!("{} must be before {}", name, bound))
+            } else {
+                Ok(())
+            }
+        }));
+        self
+    }
+
+    /// Requires the value to be strictly after (greater than) `bound`.
+    ///
+    /// This is useful for dates and times.
+    pub fn after(mut self, bound: T) -> Self {
+        self.functions.push(Box::new(move |name, value| {
+            if value <= &bound {
+                Err(format!("{} must be after {}", name, bound))
+            } else {
+                Ok(())
+            }
+        }));
+        self
+    }
+}
+
+impl<FD: FormToolData, T: PartialOrd<T> + Display + Send + Sync + 'static>
+    ValidationBuilder<FD, Option<T>>
+{
+    /// Requires the value, if there is one, to be strictly before (less
+    /// than) `bound`.
+    ///
+    /// This is [`before`](ValidationBuilder::before) for dates and times that
+    /// may be left empty. Use [`required`](Self::required) to also require a
+    /// value.
+    pub fn before_if_some(mut self, bound: T) -> Self {
+        self.functions
+            .push(Box::new(move |name, value| match value {
+                Some(value) if value >= &bound => Err(format!("{} must be before {}", name, bound)),
+                _ => Ok(()),
+            }));
+        self
+    }
+
+    /// Requires the value, if there is one, to be strictly after (greater
+    /// than) `bound`.
+    ///
+    /// This is [`after`](ValidationBuilder::after) for dates and times that
+    /// may be left empty. Use [`required`](Self::required) to also require a
+    /// value.
+    pub fn after_if_some(mut self, bound: T) -> Self {
+        self.functions
+            .push(Box::new(move |name, value| match value {
+                Some(value) if value <= &bound => Err(format!("{} must be after {}", name, bound)),
+                _ => Ok(()),
+            }));
+        self
+    }
 }
 
 impl<FD: FormToolData, T: PartialEq<T> + Display + Send + Sync + 'static> ValidationBuilder<FD, T> {
@@ -185,3 +358,143 @@ impl<FD: FormToolData, T: PartialEq<T> + Display + Send + Sync + 'static> Valida
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{form_builder::FormBuilder, styles::GridFormStyle};
+    use std::collections::HashSet;
+
+    #[derive(Clone, Default)]
+    struct Event {
+        day: u32,
+        end_day: Option<u32>,
+        tags: Vec<String>,
+        guests: HashSet<u32>,
+    }
+    impl FormToolData for Event {
+        type Style = GridFormStyle;
+        type Context = ();
+
+        fn build_form(fb: FormBuilder<Self>) -> FormBuilder<Self> {
+            fb
+        }
+    }
+
+    fn event(day: u32, end_day: Option<u32>) -> Event {
+        Event {
+            day,
+            end_day,
+            ..Default::default()
+        }
+    }
+
+    fn tagged(tags: &[&str]) -> Event {
+        Event {
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn before_and_after_are_strict() {
+        let validation = ValidationBuilder::for_field(|e: &Event| &e.day)
+            .named("Day")
+            .after(10)
+            .before(20)
+            .build();
+        assert_eq!(validation(&event(15, None)), Ok(()));
+        assert_eq!(
+            validation(&event(10, None)),
+            Err(String::from("Day must be after 10"))
+        );
+        assert_eq!(
+            validation(&event(20, None)),
+            Err(String::from("Day must be before 20"))
+        );
+    }
+
+    #[test]
+    fn if_some_bounds_skip_none() {
+        let validation = ValidationBuilder::for_field(|e: &Event| &e.end_day)
+            .named("End day")
+            .after_if_some(10)
+            .before_if_some(20)
+            .build();
+        assert_eq!(validation(&event(0, None)), Ok(()));
+        assert_eq!(validation(&event(0, Some(11))), Ok(()));
+        assert_eq!(
+            validation(&event(0, Some(10))),
+            Err(String::from("End day must be after 10"))
+        );
+        assert_eq!(
+            validation(&event(0, Some(20))),
+            Err(String::from("End day must be before 20"))
+        );
+    }
+
+    #[test]
+    fn if_some_bounds_can_be_required() {
+        let validation = ValidationBuilder::for_field(|e: &Event| &e.end_day)
+            .named("End day")
+            .required()
+            .after_if_some(10)
+            .build();
+        assert_eq!(
+            validation(&event(0, None)),
+            Err(String::from("End day is required"))
+        );
+        assert_eq!(validation(&event(0, Some(12))), Ok(()));
+    }
+
+    #[test]
+    fn item_counts_include_the_bounds() {
+        let validation = ValidationBuilder::for_field(|e: &Event| &e.tags)
+            .named("Tags")
+            .min_items(1)
+            .max_items(3)
+            .build();
+        assert_eq!(
+            validation(&tagged(&[])),
+            Err(String::from("Tags must have >= 1 items"))
+        );
+        assert_eq!(validation(&tagged(&["a"])), Ok(()));
+        assert_eq!(validation(&tagged(&["a", "b", "c"])), Ok(()));
+        assert_eq!(
+            validation(&tagged(&["a", "b", "c", "d"])),
+            Err(String::from("Tags must have <= 3 items"))
+        );
+    }
+
+    #[test]
+    fn item_counts_work_for_sets() {
+        let validation = ValidationBuilder::for_field(|e: &Event| &e.guests)
+            .named("Guests")
+            .min_items(2)
+            .build();
+        let mut event = Event::default();
+        event.guests.insert(1);
+        assert_eq!(
+            validation(&event),
+            Err(String::from("Guests must have >= 2 items"))
+        );
+        event.guests.insert(2);
+        assert_eq!(validation(&event), Ok(()));
+    }
+
+    #[test]
+    fn unique_items_rejects_duplicates() {
+        let validation = ValidationBuilder::for_field(|e: &Event| &e.tags)
+            .named("Tags")
+            .unique_items()
+            .build();
+        assert_eq!(validation(&tagged(&[])), Ok(()));
+        assert_eq!(validation(&tagged(&["a", "b", "c"])), Ok(()));
+        assert_eq!(
+            validation(&tagged(&["a", "b", "a"])),
+            Err(String::from("Tags can't have duplicate items"))
+        );
+        // items are compared exactly
+        assert_eq!(validation(&tagged(&["a", "A"])), Ok(()));
+    }
+}
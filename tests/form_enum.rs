@@ -0,0 +1,82 @@
+use leptos_form_tool::{EnumField, FormEnum};
+
+#[derive(Debug, Clone, Copy, PartialEq, leptos_form_tool_derive::FormEnum)]
+enum Size {
+    Small,
+    #[form(label = "Medium (most popular)")]
+    Medium,
+    #[form(value = "xl", label = "Extra large")]
+    ExtraLarge,
+    HTTPServer,
+    Large2X,
+    #[form(value = "custom")]
+    Custom,
+}
+
+#[test]
+fn variants_are_in_order() {
+    assert_eq!(
+        Size::variants(),
+        &[
+            Size::Small,
+            Size::Medium,
+            Size::ExtraLarge,
+            Size::HTTPServer,
+            Size::Large2X,
+            Size::Custom,
+        ]
+    );
+}
+
+#[test]
+fn values_default_to_the_name() {
+    let values = Size::variants().iter().map(Size::value).collect::<Vec<_>>();
+    assert_eq!(
+        values,
+        ["Small", "Medium", "xl", "HTTPServer", "Large2X", "custom"]
+    );
+}
+
+#[test]
+fn labels_default_to_the_split_name() {
+    let labels = Size::variants().iter().map(Size::label).collect::<Vec<_>>();
+    assert_eq!(
+        labels,
+        [
+            "Small",
+            "Medium (most popular)",
+            "Extra large",
+            "HTTP Server",
+            "Large2X",
+            "Custom",
+        ]
+    );
+}
+
+#[test]
+fn every_variant_round_trips() {
+    for variant in Size::variants() {
+        assert_eq!(Size::from_value(variant.value()), Some(*variant));
+        assert_eq!(
+            <Size as EnumField<Size>>::from_enum_value(&variant.to_enum_value()),
+            Ok(*variant)
+        );
+        assert_eq!(
+            <Option<Size> as EnumField<Size>>::from_enum_value(variant.value()),
+            Ok(Some(*variant))
+        );
+    }
+}
+
+#[test]
+fn unknown_values_are_not_variants() {
+    // the overridden value replaces the name
+    assert_eq!(Size::from_value("ExtraLarge"), None);
+    assert_eq!(Size::from_value(""), None);
+    assert!(<Size as EnumField<Size>>::from_enum_value("").is_err());
+    assert_eq!(
+        <Option<Size> as EnumField<Size>>::from_enum_value(""),
+        Ok(None)
+    );
+    assert_eq!(None::<Size>.to_enum_value(), "");
+}